@@ -9,12 +9,17 @@ show_usage() {
     echo ""
     echo "Options"
     echo "  -h                        Show help"
+    echo "  -x                        Build DataExchange module (STEP/IGES), required by the"
+    echo "                            occt crate data exchange features"
     echo ""
 }
 
-while getopts 'h' opt; do
+data_exchange=OFF
+
+while getopts 'hx' opt; do
     case "$opt" in
         h) show_usage; exit 0 ;;
+        x) data_exchange=ON ;;
         *) show_usage; exit 1 ;;
     esac
 done
@@ -89,12 +94,13 @@ cmake_cfg=(
     "-DCMAKE_BUILD_TYPE=Release"
     "-DBUILD_LIBRARY_TYPE:STRING=Static"
     "-DBUILD_CPP_STANDARD:STRING=C++17"
-    "-DBUILD_RELEASE_DISABLE_EXCEPTIONS:BOOL=ON"
+    # Keep OCCT argument checks in release, the bridge reports them as errors
+    "-DBUILD_RELEASE_DISABLE_EXCEPTIONS:BOOL=OFF"
     "-DUSE_TK:BOOL=OFF"
     "-DUSE_FREETYPE:BOOL=OFF"
     "-DUSE_OPENGL:BOOL=OFF"
     "-DBUILD_MODULE_Draw:BOOL=OFF"
-    "-DBUILD_MODULE_DataExchange:BOOL=OFF"
+    "-DBUILD_MODULE_DataExchange:BOOL=$data_exchange"
     "-DBUILD_MODULE_ModelingData:BOOL=OFF"
     "-DBUILD_MODULE_Visualization:BOOL=OFF"
 )
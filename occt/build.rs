@@ -17,12 +17,26 @@ const OCCT_LIBS: &[&str] = &[
     "TKBO",
     "TKOffset"
 ];
+const OCCT_EXCHANGE_LIBS: &[&str] = &[
+    "TKXSBase"
+];
+const OCCT_STEP_LIBS: &[&str] = &[
+    "TKSTEPBase",
+    "TKSTEPAttr",
+    "TKSTEP209",
+    "TKSTEP"
+];
+const OCCT_IGES_LIBS: &[&str] = &[
+    "TKIGES"
+];
 
 use std::env;
 
 fn main() {
     let dir = env::var("CARGO_MANIFEST_DIR").unwrap();
     let occt_dir = format!("{dir}/{OCCT_DIR}");
+    let step = env::var_os("CARGO_FEATURE_STEP").is_some();
+    let iges = env::var_os("CARGO_FEATURE_IGES").is_some();
 
     println!("cargo:rustc-link-search=native={occt_dir}/lib");
     println!("cargo:rustc-link-search=native={occt_dir}/win64/vc14/lib");
@@ -31,9 +45,53 @@ fn main() {
         println!("cargo:rustc-link-lib=static={lib}");
     }
 
-    cxx_build::bridge("src/occt.rs")
+    let mut bridges = vec!["src/occt.rs"];
+    let mut sources = vec![];
+
+    if step || iges {
+        for lib in OCCT_EXCHANGE_LIBS {
+            println!("cargo:rustc-link-lib=static={lib}");
+        }
+    }
+
+    if step {
+        for lib in OCCT_STEP_LIBS {
+            println!("cargo:rustc-link-lib=static={lib}");
+        }
+
+        bridges.push("src/step.rs");
+        sources.push("src/step.cpp");
+    }
+
+    if iges {
+        for lib in OCCT_IGES_LIBS {
+            println!("cargo:rustc-link-lib=static={lib}");
+        }
+
+        bridges.push("src/iges.rs");
+        sources.push("src/iges.cpp");
+    }
+
+    let mut build = cxx_build::bridges(bridges);
+
+    build
         .cpp(true)
+        .file("src/boolean.cpp")
+        .file("src/brep.cpp")
+        .file("src/classify.cpp")
+        .file("src/distance.cpp")
+        .file("src/fillet.cpp")
+        .file("src/flask.cpp")
+        .file("src/geometry.cpp")
         .file("src/occt.cpp")
+        .file("src/primitives.cpp")
+        .file("src/props.cpp")
+        .file("src/sewing.cpp")
+        .file("src/shape.cpp")
+        .file("src/sketch.cpp")
+        .file("src/sweep.cpp")
+        .file("src/topology.cpp")
+        .files(sources)
         .std("c++17")
         .include(format!("{occt_dir}/include/opencascade"))
         .include(format!("{occt_dir}/inc"))
@@ -42,4 +100,26 @@ fn main() {
     println!("cargo:rerun-if-changed=src/occt.rs");
     println!("cargo:rerun-if-changed=src/occt.cpp");
     println!("cargo:rerun-if-changed=src/occt.h");
+    println!("cargo:rerun-if-changed=src/occt_p.h");
+    println!("cargo:rerun-if-changed=src/boolean.cpp");
+    println!("cargo:rerun-if-changed=src/brep.cpp");
+    println!("cargo:rerun-if-changed=src/classify.cpp");
+    println!("cargo:rerun-if-changed=src/distance.cpp");
+    println!("cargo:rerun-if-changed=src/fillet.cpp");
+    println!("cargo:rerun-if-changed=src/flask.cpp");
+    println!("cargo:rerun-if-changed=src/geometry.cpp");
+    println!("cargo:rerun-if-changed=src/primitives.cpp");
+    println!("cargo:rerun-if-changed=src/props.cpp");
+    println!("cargo:rerun-if-changed=src/sewing.cpp");
+    println!("cargo:rerun-if-changed=src/shape.cpp");
+    println!("cargo:rerun-if-changed=src/sketch.cpp");
+    println!("cargo:rerun-if-changed=src/sweep.cpp");
+    println!("cargo:rerun-if-changed=src/topology.cpp");
+    println!("cargo:rerun-if-changed=src/exchange_p.h");
+    println!("cargo:rerun-if-changed=src/step.rs");
+    println!("cargo:rerun-if-changed=src/step.h");
+    println!("cargo:rerun-if-changed=src/step.cpp");
+    println!("cargo:rerun-if-changed=src/iges.rs");
+    println!("cargo:rerun-if-changed=src/iges.h");
+    println!("cargo:rerun-if-changed=src/iges.cpp");
 }
\ No newline at end of file
@@ -0,0 +1,103 @@
+#include "occt/src/occt_p.h"
+
+#include <sstream>
+
+#include <BRepAlgoAPI_Common.hxx>
+#include <BRepAlgoAPI_Cut.hxx>
+#include <BRepAlgoAPI_Fuse.hxx>
+#include <BRepAlgoAPI_Section.hxx>
+#include <Message_Alert.hxx>
+#include <Message_Report.hxx>
+#include <TopoDS_Iterator.hxx>
+
+namespace occt {
+
+namespace {
+
+std::unique_ptr<BRepAlgoAPI_BooleanOperation> make_algo(BooleanOp op)
+{
+    switch(op)
+    {
+    case BooleanOp::Fuse:
+        return std::make_unique<BRepAlgoAPI_Fuse>();
+    case BooleanOp::Cut:
+        return std::make_unique<BRepAlgoAPI_Cut>();
+    case BooleanOp::Common:
+        return std::make_unique<BRepAlgoAPI_Common>();
+    case BooleanOp::Section:
+        return std::make_unique<BRepAlgoAPI_Section>();
+    }
+
+    throw Error::invalid_input("Unknown boolean operation");
+}
+
+BOPAlgo_GlueEnum to_glue(GlueMode glue)
+{
+    switch(glue)
+    {
+    case GlueMode::Shift:
+        return BOPAlgo_GlueShift;
+    case GlueMode::Full:
+        return BOPAlgo_GlueFull;
+    default:
+        return BOPAlgo_GlueOff;
+    }
+}
+
+bool is_empty(const TopoDS_Shape& shape)
+{
+    return shape.IsNull() || (shape.ShapeType() == TopAbs_COMPOUND && !TopoDS_Iterator(shape).More());
+}
+
+} // namespace
+
+std::unique_ptr<Shape> boolean_op(BooleanOp op, const ShapeList& args, const ShapeList& tools, const BooleanOptions& options, rust::Vec<rust::String>& warnings)
+{
+    check_input(!args.items().empty() && !tools.items().empty(), "Boolean operation requires arguments and tools");
+    check_input(options.fuzzy_value >= 0.0, "Fuzzy value must not be negative");
+
+    std::unique_ptr<BRepAlgoAPI_BooleanOperation> algo = make_algo(op);
+
+    algo->SetArguments(to_list(args));
+    algo->SetTools(to_list(tools));
+    algo->SetFuzzyValue(options.fuzzy_value);
+    algo->SetRunParallel(options.parallel);
+    algo->SetGlue(to_glue(options.glue));
+    // Keep arguments untouched, they may be shared with other Shape handles
+    algo->SetNonDestructive(Standard_True);
+    algo->Build();
+
+    if(algo->HasErrors())
+    {
+        std::ostringstream os;
+
+        os << "Boolean operation failed:";
+        algo->DumpErrors(os);
+
+        throw Error::not_done(os.str());
+    }
+
+    const TopoDS_Shape& result = algo->Shape();
+
+    if(is_empty(result) && algo->HasWarnings())
+    {
+        std::ostringstream os;
+
+        os << "Boolean operation produced empty result:";
+        algo->DumpWarnings(os);
+
+        throw Error::not_done(os.str());
+    }
+
+    // Non-empty results may still carry warnings, e.g. self-interfering arguments
+    const Message_ListOfAlert& alerts = algo->GetReport()->GetAlerts(Message_Warning);
+
+    for(Message_ListOfAlert::Iterator alert_i(alerts); alert_i.More(); alert_i.Next())
+    {
+        warnings.push_back(rust::String(alert_i.Value()->GetMessageKey()));
+    }
+
+    return std::make_unique<Shape>(result);
+}
+
+} // namespace occt
@@ -0,0 +1,130 @@
+use cxx::UniquePtr;
+
+use error::{ErrorKind, OcctError};
+use occt::ffi;
+use shape::Shape;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BooleanOp {
+    Fuse,
+    Cut,
+    Common,
+    Section,
+}
+
+/// Gluing mode for arguments sharing coinciding sub-shapes (`BOPAlgo_GlueEnum`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Glue {
+    Off,
+    Shift,
+    Full,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BooleanOptions {
+    /// Additional tolerance used to treat near-coincident geometry as coincident.
+    pub fuzzy_value: f64,
+    pub parallel: bool,
+    pub glue: Glue,
+}
+
+impl Default for BooleanOptions {
+    fn default() -> Self {
+        Self {
+            fuzzy_value: 0.0,
+            parallel: false,
+            glue: Glue::Off,
+        }
+    }
+}
+
+/// Result of a boolean operation with the `BOPAlgo` warnings it raised.
+#[derive(Clone)]
+pub struct BooleanResult {
+    pub shape: Shape,
+    /// Alert names such as `BOPAlgo_AlertSelfInterferingShape`, empty for a
+    /// clean run.
+    pub warnings: Vec<String>,
+}
+
+pub(crate) fn shape_list(shapes: &[&Shape]) -> UniquePtr<ffi::ShapeList> {
+    let mut list = ffi::new_shape_list();
+
+    for shape in shapes {
+        list.pin_mut().push(shape.as_ffi());
+    }
+
+    list
+}
+
+/// Runs `op` of `args` against `tools`, e.g. cuts every tool out of every argument.
+/// Errors and empty results with warnings fail, warnings on a usable result
+/// are returned with it.
+pub fn boolean(
+    op: BooleanOp,
+    args: &[&Shape],
+    tools: &[&Shape],
+    options: &BooleanOptions,
+) -> Result<BooleanResult, OcctError> {
+    let op = match op {
+        BooleanOp::Fuse => ffi::BooleanOp::Fuse,
+        BooleanOp::Cut => ffi::BooleanOp::Cut,
+        BooleanOp::Common => ffi::BooleanOp::Common,
+        BooleanOp::Section => ffi::BooleanOp::Section,
+    };
+    let options = ffi::BooleanOptions {
+        fuzzy_value: options.fuzzy_value,
+        parallel: options.parallel,
+        glue: match options.glue {
+            Glue::Off => ffi::GlueMode::Off,
+            Glue::Shift => ffi::GlueMode::Shift,
+            Glue::Full => ffi::GlueMode::Full,
+        },
+    };
+
+    let mut warnings = Vec::new();
+    let shape = ffi::boolean_op(
+        op,
+        &shape_list(args),
+        &shape_list(tools),
+        &options,
+        &mut warnings,
+    )?;
+
+    Ok(BooleanResult {
+        shape: Shape::from_ffi(shape),
+        warnings,
+    })
+}
+
+pub fn fuse(a: &Shape, b: &Shape, options: &BooleanOptions) -> Result<BooleanResult, OcctError> {
+    boolean(BooleanOp::Fuse, &[a], &[b], options)
+}
+
+pub fn cut(a: &Shape, b: &Shape, options: &BooleanOptions) -> Result<BooleanResult, OcctError> {
+    boolean(BooleanOp::Cut, &[a], &[b], options)
+}
+
+pub fn common(a: &Shape, b: &Shape, options: &BooleanOptions) -> Result<BooleanResult, OcctError> {
+    boolean(BooleanOp::Common, &[a], &[b], options)
+}
+
+/// Intersection curves and points of `a` and `b` as a compound of edges and vertices.
+pub fn section(a: &Shape, b: &Shape, options: &BooleanOptions) -> Result<BooleanResult, OcctError> {
+    boolean(BooleanOp::Section, &[a], &[b], options)
+}
+
+/// Fuses all `shapes` in a single operation, which is faster and more robust than
+/// fusing them pairwise.
+pub fn fuse_all(shapes: &[&Shape], options: &BooleanOptions) -> Result<BooleanResult, OcctError> {
+    match shapes.split_first() {
+        Some((first, rest)) if !rest.is_empty() => {
+            boolean(BooleanOp::Fuse, &[first], rest, options)
+        }
+        Some((first, _)) => Ok(BooleanResult {
+            shape: (*first).clone(),
+            warnings: Vec::new(),
+        }),
+        None => Err(OcctError::new(ErrorKind::InvalidInput, "Nothing to fuse")),
+    }
+}
@@ -0,0 +1,110 @@
+#include "occt/src/occt_p.h"
+
+#include <sstream>
+#include <string>
+
+#include <BinTools.hxx>
+#include <BRep_Builder.hxx>
+#include <BRepTools.hxx>
+
+namespace occt {
+
+void write_brep(const Shape& shape, rust::Str path)
+{
+    check_input(!shape.is_null(), "Can't write null shape");
+
+    const std::string file(path);
+
+    if(!BRepTools::Write(shape.get(), file.c_str()))
+    {
+        throw Error::io("Failed to write BRep file " + file);
+    }
+}
+
+std::unique_ptr<Shape> read_brep(rust::Str path)
+{
+    const std::string file(path);
+    TopoDS_Shape shape;
+
+    if(!BRepTools::Read(shape, file.c_str(), BRep_Builder()) || shape.IsNull())
+    {
+        throw Error::io("Failed to read BRep file " + file);
+    }
+
+    return std::make_unique<Shape>(shape);
+}
+
+rust::String write_brep_string(const Shape& shape)
+{
+    check_input(!shape.is_null(), "Can't write null shape");
+
+    std::ostringstream os;
+
+    BRepTools::Write(shape.get(), os);
+
+    if(!os)
+    {
+        throw Error::io("Failed to write BRep");
+    }
+
+    return rust::String(os.str());
+}
+
+std::unique_ptr<Shape> read_brep_string(rust::Str text)
+{
+    std::istringstream is(std::string(text));
+    TopoDS_Shape shape;
+
+    BRepTools::Read(shape, is, BRep_Builder());
+
+    if(shape.IsNull())
+    {
+        throw Error::io("Failed to read BRep");
+    }
+
+    return std::make_unique<Shape>(shape);
+}
+
+rust::Vec<std::uint8_t> write_brep_bytes(const Shape& shape)
+{
+    check_input(!shape.is_null(), "Can't write null shape");
+
+    std::ostringstream os(std::ios::out | std::ios::binary);
+
+    BinTools::Write(shape.get(), os);
+
+    if(!os)
+    {
+        throw Error::io("Failed to write binary BRep");
+    }
+
+    const std::string data = os.str();
+    rust::Vec<std::uint8_t> bytes;
+
+    bytes.reserve(data.size());
+
+    for(char c : data)
+    {
+        bytes.push_back(static_cast<std::uint8_t>(c));
+    }
+
+    return bytes;
+}
+
+std::unique_ptr<Shape> read_brep_bytes(rust::Slice<const std::uint8_t> bytes)
+{
+    std::istringstream is(std::string(reinterpret_cast<const char*>(bytes.data()), bytes.size()),
+        std::ios::in | std::ios::binary);
+    TopoDS_Shape shape;
+
+    BinTools::Read(shape, is);
+
+    if(shape.IsNull())
+    {
+        throw Error::io("Failed to read binary BRep");
+    }
+
+    return std::make_unique<Shape>(shape);
+}
+
+} // namespace occt
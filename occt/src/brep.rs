@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use error::{path_str, OcctError};
+use occt::ffi;
+use shape::Shape;
+
+/// Native OCCT BRep persistence, exact geometry round-trips without loss.
+impl Shape {
+    pub fn write_brep<P: AsRef<Path>>(&self, path: P) -> Result<(), OcctError> {
+        Ok(ffi::write_brep(self.as_ffi(), path_str(path.as_ref())?)?)
+    }
+
+    pub fn read_brep<P: AsRef<Path>>(path: P) -> Result<Shape, OcctError> {
+        Ok(Shape::from_ffi(ffi::read_brep(path_str(path.as_ref())?)?))
+    }
+
+    /// ASCII BRep, same content as `write_brep` produces.
+    pub fn to_brep_string(&self) -> Result<String, OcctError> {
+        Ok(ffi::write_brep_string(self.as_ffi())?)
+    }
+
+    pub fn from_brep_str(text: &str) -> Result<Shape, OcctError> {
+        Ok(Shape::from_ffi(ffi::read_brep_string(text)?))
+    }
+
+    /// Compact binary BRep (`BinTools`), faster to parse than the ASCII form.
+    pub fn to_brep_bytes(&self) -> Result<Vec<u8>, OcctError> {
+        Ok(ffi::write_brep_bytes(self.as_ffi())?)
+    }
+
+    pub fn from_brep_bytes(bytes: &[u8]) -> Result<Shape, OcctError> {
+        Ok(Shape::from_ffi(ffi::read_brep_bytes(bytes)?))
+    }
+}
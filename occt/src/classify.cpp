@@ -0,0 +1,43 @@
+#include "occt/src/occt_p.h"
+
+#include <BRepClass3d_SolidClassifier.hxx>
+
+namespace occt {
+
+rust::Vec<PointState> classify_points(const Shape& solid, rust::Slice<const Tuple3d> points, double tolerance)
+{
+    check_input(!solid.is_null(), "Can't classify points against null shape");
+    check_input(solid.get().ShapeType() == TopAbs_SOLID, "Points can only be classified against a solid");
+    check_input(tolerance > 0.0, "Classification tolerance must be positive");
+
+    // Loading the solid is the expensive part, it is shared by all points of the batch
+    BRepClass3d_SolidClassifier classifier(solid.get());
+    rust::Vec<PointState> states;
+
+    states.reserve(points.size());
+
+    for(const Tuple3d& point : points)
+    {
+        classifier.Perform(to_pnt(point), tolerance);
+
+        switch(classifier.State())
+        {
+        case TopAbs_IN:
+            states.push_back(PointState::Inside);
+            break;
+        case TopAbs_OUT:
+            states.push_back(PointState::Outside);
+            break;
+        case TopAbs_ON:
+            states.push_back(PointState::OnBoundary);
+            break;
+        default:
+            states.push_back(PointState::Unknown);
+            break;
+        }
+    }
+
+    return states;
+}
+
+} // namespace occt
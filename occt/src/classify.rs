@@ -0,0 +1,147 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+
+use glam::DVec3;
+use threadpool::ThreadPool;
+
+use error::{ErrorKind, OcctError};
+use occt::ffi;
+use shape::Shape;
+
+/// Location of a point relative to a solid (`TopAbs_State`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointState {
+    Inside,
+    Outside,
+    OnBoundary,
+    Unknown,
+}
+
+impl From<ffi::PointState> for PointState {
+    fn from(state: ffi::PointState) -> Self {
+        match state {
+            ffi::PointState::Inside => PointState::Inside,
+            ffi::PointState::Outside => PointState::Outside,
+            ffi::PointState::OnBoundary => PointState::OnBoundary,
+            _ => PointState::Unknown,
+        }
+    }
+}
+
+/// Smallest batch handed to a pool thread, classifier setup dominates below it.
+const MIN_CHUNK: usize = 256;
+
+type ChunkResult = (usize, Result<Vec<PointState>, OcctError>);
+
+/// Points of a parallel batch, chunks are claimed in order by whichever
+/// thread gets to them first.
+struct Batch {
+    points: Vec<ffi::Tuple3d>,
+    chunk_size: usize,
+    next: AtomicUsize,
+}
+
+impl Batch {
+    fn chunk_count(&self) -> usize {
+        self.points.len().div_ceil(self.chunk_size)
+    }
+
+    /// Classifies chunks until none are left unclaimed.
+    fn run(&self, solid: &Shape, tolerance: f64, tx: &mpsc::Sender<ChunkResult>) {
+        loop {
+            let i = self.next.fetch_add(1, Ordering::Relaxed);
+            let start = i * self.chunk_size;
+
+            if start >= self.points.len() {
+                break;
+            }
+
+            let chunk = &self.points[start..(start + self.chunk_size).min(self.points.len())];
+
+            // A panic would leave the caller waiting for this chunk, report it instead
+            let result =
+                panic::catch_unwind(AssertUnwindSafe(|| classify(solid, chunk, tolerance)))
+                    .unwrap_or_else(|_| {
+                        Err(OcctError::new(
+                            ErrorKind::Failure,
+                            "Point classification job failed",
+                        ))
+                    });
+
+            let _ = tx.send((i, result));
+        }
+    }
+}
+
+fn classify(
+    solid: &Shape,
+    points: &[ffi::Tuple3d],
+    tolerance: f64,
+) -> Result<Vec<PointState>, OcctError> {
+    let states = ffi::classify_points(solid.as_ffi(), points, tolerance)?;
+
+    Ok(states.into_iter().map(PointState::from).collect())
+}
+
+impl Shape {
+    /// Locates `point` relative to a solid (`BRepClass3d_SolidClassifier`),
+    /// points closer than `tolerance` to the boundary are `OnBoundary`.
+    pub fn classify_point(&self, point: DVec3, tolerance: f64) -> Result<PointState, OcctError> {
+        Ok(self.classify_points(&[point], tolerance)?[0])
+    }
+
+    /// Classifies a batch of points on the calling thread, states are in input order.
+    /// Fails for shapes other than solids.
+    pub fn classify_points(
+        &self,
+        points: &[DVec3],
+        tolerance: f64,
+    ) -> Result<Vec<PointState>, OcctError> {
+        let raw: Vec<ffi::Tuple3d> = points.iter().map(|&p| p.into()).collect();
+
+        classify(self, &raw, tolerance)
+    }
+
+    /// Same as `classify_points`, split into chunks shared between `pool` and
+    /// the calling thread.
+    ///
+    /// The calling thread classifies chunks too and only waits for chunks a
+    /// pool thread has already started, so a busy pool slows the call down
+    /// but can't block it.
+    pub fn classify_points_parallel(
+        &self,
+        points: &[DVec3],
+        tolerance: f64,
+        pool: &ThreadPool,
+    ) -> Result<Vec<PointState>, OcctError> {
+        let batch = Arc::new(Batch {
+            points: points.iter().map(|&p| p.into()).collect(),
+            chunk_size: points.len().div_ceil(pool.max_count() + 1).max(MIN_CHUNK),
+            next: AtomicUsize::new(0),
+        });
+        let chunks = batch.chunk_count();
+        let (tx, rx) = mpsc::channel();
+
+        for _ in 1..chunks {
+            // Clones share the B-rep, each job loads its own classifier
+            let solid = self.clone();
+            let batch = batch.clone();
+            let tx = tx.clone();
+
+            pool.execute(move || batch.run(&solid, tolerance, &tx));
+        }
+
+        batch.run(self, tolerance, &tx);
+
+        // Jobs still queued hold senders but find nothing left, so count
+        // results instead of waiting for the channel to close
+        let mut results: Vec<Option<Vec<PointState>>> = vec![None; chunks];
+
+        for (i, result) in rx.iter().take(chunks) {
+            results[i] = Some(result?);
+        }
+
+        Ok(results.into_iter().flatten().flatten().collect())
+    }
+}
@@ -0,0 +1,144 @@
+#include "occt/src/occt_p.h"
+
+#include <BRepBuilderAPI_MakeVertex.hxx>
+#include <BRepExtrema_DistShapeShape.hxx>
+#include <TopExp.hxx>
+#include <TopTools_IndexedMapOfShape.hxx>
+
+namespace occt {
+
+namespace {
+
+// Lazily built sub-shape maps of one argument, numbering supports like the topology queries
+struct SupportMaps
+{
+    explicit SupportMaps(const TopoDS_Shape& shape) : shape(shape)
+    {
+    }
+
+    std::uint32_t index(const TopoDS_Shape& sub, TopAbs_ShapeEnum type)
+    {
+        TopTools_IndexedMapOfShape& map = type == TopAbs_VERTEX ? vertices : type == TopAbs_EDGE ? edges : faces;
+
+        if(map.IsEmpty())
+        {
+            TopExp::MapShapes(shape, type, map);
+        }
+
+        const int index = map.FindIndex(sub);
+
+        check_input(index > 0, "Solution support is not a sub-shape of the argument");
+
+        return static_cast<std::uint32_t>(index - 1);
+    }
+
+    const TopoDS_Shape& shape;
+    TopTools_IndexedMapOfShape vertices;
+    TopTools_IndexedMapOfShape edges;
+    TopTools_IndexedMapOfShape faces;
+};
+
+DistanceSupport to_support(BRepExtrema_DistShapeShape& dist, int n, bool first, SupportMaps& maps)
+{
+    const BRepExtrema_SupportType type = first ? dist.SupportTypeShape1(n) : dist.SupportTypeShape2(n);
+    const TopoDS_Shape sub = first ? dist.SupportOnShape1(n) : dist.SupportOnShape2(n);
+    DistanceSupport support;
+
+    support.params = {0.0, 0.0};
+
+    switch(type)
+    {
+    case BRepExtrema_IsOnEdge:
+        support.kind = ShapeType::Edge;
+        support.index = maps.index(sub, TopAbs_EDGE);
+
+        if(first)
+        {
+            dist.ParOnEdgeS1(n, support.params.x);
+        }
+        else
+        {
+            dist.ParOnEdgeS2(n, support.params.x);
+        }
+
+        break;
+    case BRepExtrema_IsInFace:
+        support.kind = ShapeType::Face;
+        support.index = maps.index(sub, TopAbs_FACE);
+
+        if(first)
+        {
+            dist.ParOnFaceS1(n, support.params.x, support.params.y);
+        }
+        else
+        {
+            dist.ParOnFaceS2(n, support.params.x, support.params.y);
+        }
+
+        break;
+    default:
+        support.kind = ShapeType::Vertex;
+        support.index = maps.index(sub, TopAbs_VERTEX);
+        break;
+    }
+
+    return support;
+}
+
+DistanceData compute_distance(const TopoDS_Shape& shape1, const TopoDS_Shape& shape2, const DistanceParams& params)
+{
+    check_input(params.deflection > 0.0, "Distance deflection must be positive");
+
+    BRepExtrema_DistShapeShape dist;
+
+    dist.SetDeflection(params.deflection);
+    dist.SetMultiThread(params.parallel);
+    dist.LoadS1(shape1);
+    dist.LoadS2(shape2);
+
+    if(!dist.Perform() || !dist.IsDone())
+    {
+        throw Error::not_done("Failed to compute distance");
+    }
+
+    SupportMaps maps1(shape1);
+    SupportMaps maps2(shape2);
+    DistanceData data;
+
+    data.value = dist.Value();
+    data.inner = dist.InnerSolution();
+
+    for(int n = 1; n <= dist.NbSolution(); ++n)
+    {
+        DistanceSolution solution;
+
+        solution.point1 = from_xyz(dist.PointOnShape1(n).XYZ());
+        solution.point2 = from_xyz(dist.PointOnShape2(n).XYZ());
+        solution.support1 = to_support(dist, n, true, maps1);
+        solution.support2 = to_support(dist, n, false, maps2);
+
+        data.solutions.push_back(solution);
+    }
+
+    return data;
+}
+
+} // namespace
+
+DistanceData shape_distance(const Shape& shape1, const Shape& shape2, const DistanceParams& params)
+{
+    check_input(!shape1.is_null() && !shape2.is_null(), "Can't compute distance to null shape");
+
+    return compute_distance(shape1.get(), shape2.get(), params);
+}
+
+DistanceData point_distance(const Shape& shape, const Tuple3d& point, const DistanceParams& params)
+{
+    check_input(!shape.is_null(), "Can't compute distance to null shape");
+
+    BRepBuilderAPI_MakeVertex mk_vertex(to_pnt(point));
+
+    return compute_distance(shape.get(), check_done(mk_vertex, "Failed to make vertex"), params);
+}
+
+} // namespace occt
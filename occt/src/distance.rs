@@ -0,0 +1,121 @@
+use glam::DVec3;
+
+use error::OcctError;
+use occt::ffi;
+use shape::Shape;
+
+/// Sub-shape a closest point lies on, indices are positions in the argument's
+/// vertex, edge or face map as used by the topology queries.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Support {
+    Vertex(usize),
+    Edge { index: usize, t: f64 },
+    Face { index: usize, u: f64, v: f64 },
+}
+
+impl From<ffi::DistanceSupport> for Support {
+    fn from(raw: ffi::DistanceSupport) -> Self {
+        let index = raw.index as usize;
+
+        match raw.kind {
+            ffi::ShapeType::Edge => Support::Edge {
+                index,
+                t: raw.params.x,
+            },
+            ffi::ShapeType::Face => Support::Face {
+                index,
+                u: raw.params.x,
+                v: raw.params.y,
+            },
+            _ => Support::Vertex(index),
+        }
+    }
+}
+
+/// Pair of closest points, `point1` on the first argument and `point2` on the second.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DistanceSolution {
+    pub point1: DVec3,
+    pub point2: DVec3,
+    pub support1: Support,
+    pub support2: Support,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistanceResult {
+    pub distance: f64,
+    /// One argument lies inside a solid of the other, `distance` is zero.
+    pub inner: bool,
+    /// All point pairs at `distance`.
+    pub solutions: Vec<DistanceSolution>,
+}
+
+impl From<ffi::DistanceData> for DistanceResult {
+    fn from(raw: ffi::DistanceData) -> Self {
+        Self {
+            distance: raw.value,
+            inner: raw.inner,
+            solutions: raw
+                .solutions
+                .into_iter()
+                .map(|s| DistanceSolution {
+                    point1: s.point1.into(),
+                    point2: s.point2.into(),
+                    support1: s.support1.into(),
+                    support2: s.support2.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Settings for `BRepExtrema_DistShapeShape`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DistanceOptions {
+    /// Precision used to compare candidate distances.
+    pub deflection: f64,
+    pub parallel: bool,
+}
+
+impl Default for DistanceOptions {
+    fn default() -> Self {
+        Self {
+            deflection: 1e-7,
+            parallel: false,
+        }
+    }
+}
+
+impl DistanceOptions {
+    fn to_ffi(self) -> ffi::DistanceParams {
+        ffi::DistanceParams {
+            deflection: self.deflection,
+            parallel: self.parallel,
+        }
+    }
+}
+
+/// Minimum distance between two shapes with all closest point pairs.
+pub fn distance(shape1: &Shape, shape2: &Shape) -> Result<DistanceResult, OcctError> {
+    distance_with(shape1, shape2, &DistanceOptions::default())
+}
+
+pub fn distance_with(
+    shape1: &Shape,
+    shape2: &Shape,
+    options: &DistanceOptions,
+) -> Result<DistanceResult, OcctError> {
+    Ok(ffi::shape_distance(shape1.as_ffi(), shape2.as_ffi(), &options.to_ffi())?.into())
+}
+
+impl Shape {
+    /// Minimum distance from `point`, solutions have the shape as first argument
+    /// and the point as second.
+    pub fn distance_to_point(
+        &self,
+        point: DVec3,
+        options: &DistanceOptions,
+    ) -> Result<DistanceResult, OcctError> {
+        Ok(ffi::point_distance(self.as_ffi(), &point.into(), &options.to_ffi())?.into())
+    }
+}
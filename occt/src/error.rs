@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Arguments rejected before running any algorithm.
+    InvalidInput,
+    /// OCCT algorithm finished without a result.
+    NotDone,
+    /// `Standard_Failure` or another exception raised inside OCCT.
+    Failure,
+    /// Tessellation failed or produced nothing.
+    Mesh,
+    /// File or stream could not be read or written.
+    Io,
+    /// Data exchange translator (STEP, IGES) rejected the content.
+    Translation,
+}
+
+impl ErrorKind {
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "invalid_input" => Some(ErrorKind::InvalidInput),
+            "not_done" => Some(ErrorKind::NotDone),
+            "failure" => Some(ErrorKind::Failure),
+            "mesh" => Some(ErrorKind::Mesh),
+            "io" => Some(ErrorKind::Io),
+            "translation" => Some(ErrorKind::Translation),
+            _ => None,
+        }
+    }
+}
+
+/// Failure reported by OCCT through the bridge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OcctError {
+    kind: ErrorKind,
+    message: String,
+}
+
+impl OcctError {
+    pub(crate) fn new(kind: ErrorKind, message: &str) -> Self {
+        Self {
+            kind,
+            message: message.to_string(),
+        }
+    }
+
+    // C++ side prefixes messages with "<kind>: ", see occt_p.h
+    fn from_what(what: &str) -> Self {
+        match what.split_once(": ") {
+            Some((tag, message)) => match ErrorKind::from_tag(tag) {
+                Some(kind) => Self::new(kind, message),
+                None => Self::new(ErrorKind::Failure, what),
+            },
+            None => Self::new(ErrorKind::Failure, what),
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for OcctError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+impl Error for OcctError {}
+
+impl From<cxx::Exception> for OcctError {
+    fn from(e: cxx::Exception) -> Self {
+        Self::from_what(e.what())
+    }
+}
+
+impl From<io::Error> for OcctError {
+    fn from(e: io::Error) -> Self {
+        Self::new(ErrorKind::Io, &e.to_string())
+    }
+}
+
+pub(crate) fn path_str(path: &Path) -> Result<&str, OcctError> {
+    path.to_str()
+        .ok_or_else(|| OcctError::new(ErrorKind::InvalidInput, "Path is not valid UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_kind() {
+        let kinds = [
+            ("invalid_input", ErrorKind::InvalidInput),
+            ("not_done", ErrorKind::NotDone),
+            ("failure", ErrorKind::Failure),
+            ("mesh", ErrorKind::Mesh),
+            ("io", ErrorKind::Io),
+            ("translation", ErrorKind::Translation),
+        ];
+
+        for (tag, kind) in kinds {
+            let err = OcctError::from_what(&format!("{tag}: Something went wrong"));
+
+            assert_eq!(err.kind(), kind);
+            assert_eq!(err.message(), "Something went wrong");
+        }
+    }
+
+    #[test]
+    fn unprefixed_message_is_failure() {
+        let err = OcctError::from_what("Something went wrong");
+
+        assert_eq!(err.kind(), ErrorKind::Failure);
+        assert_eq!(err.message(), "Something went wrong");
+    }
+
+    #[test]
+    fn unknown_prefix_keeps_whole_message() {
+        let err = OcctError::from_what("Standard_Failure: Something went wrong");
+
+        assert_eq!(err.kind(), ErrorKind::Failure);
+        assert_eq!(err.message(), "Standard_Failure: Something went wrong");
+    }
+
+    #[test]
+    fn splits_on_first_separator_only() {
+        let err = OcctError::from_what("failure: Standard_DomainError: Curve is not closed");
+
+        assert_eq!(err.kind(), ErrorKind::Failure);
+        assert_eq!(err.message(), "Standard_DomainError: Curve is not closed");
+
+        let err = OcctError::from_what("io: Can't open file: a.step");
+
+        assert_eq!(err.kind(), ErrorKind::Io);
+        assert_eq!(err.message(), "Can't open file: a.step");
+    }
+}
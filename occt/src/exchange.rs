@@ -0,0 +1,37 @@
+/// Length unit of model coordinates for data exchange, OCCT itself works in millimeters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LengthUnit {
+    Micrometer,
+    #[default]
+    Millimeter,
+    Centimeter,
+    Meter,
+    Inch,
+    Foot,
+}
+
+impl LengthUnit {
+    /// Size of one unit in millimeters.
+    pub fn millimeters(self) -> f64 {
+        match self {
+            LengthUnit::Micrometer => 0.001,
+            LengthUnit::Millimeter => 1.0,
+            LengthUnit::Centimeter => 10.0,
+            LengthUnit::Meter => 1000.0,
+            LengthUnit::Inch => 25.4,
+            LengthUnit::Foot => 304.8,
+        }
+    }
+
+    /// Unit name as understood by the `write.*.unit` translator parameters.
+    pub(crate) fn xstep_name(self) -> &'static str {
+        match self {
+            LengthUnit::Micrometer => "UM",
+            LengthUnit::Millimeter => "MM",
+            LengthUnit::Centimeter => "CM",
+            LengthUnit::Meter => "M",
+            LengthUnit::Inch => "INCH",
+            LengthUnit::Foot => "FT",
+        }
+    }
+}
@@ -0,0 +1,38 @@
+#pragma once
+
+#include "occt/src/occt_p.h"
+
+#include <mutex>
+
+#include <BRepBuilderAPI_Transform.hxx>
+#include <gp_Trsf.hxx>
+
+namespace occt {
+
+// Translator parameters (Interface_Static) are process wide, hold this lock
+// while setting them and running a translation
+inline std::mutex& xstep_mutex()
+{
+    static std::mutex mutex;
+
+    return mutex;
+}
+
+// Converts between model units and the millimeters OCCT translators work in
+inline TopoDS_Shape scale_shape(const TopoDS_Shape& shape, double factor)
+{
+    if(factor == 1.0)
+    {
+        return shape;
+    }
+
+    gp_Trsf trsf;
+
+    trsf.SetScale(gp::Origin(), factor);
+
+    BRepBuilderAPI_Transform mk_transform(shape, trsf, Standard_True);
+
+    return check_done(mk_transform, "Unit conversion failed");
+}
+
+} // namespace occt
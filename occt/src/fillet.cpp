@@ -0,0 +1,180 @@
+#include "occt/src/occt_p.h"
+
+#include <sstream>
+#include <string>
+
+#include <BRepFilletAPI_MakeChamfer.hxx>
+#include <BRepFilletAPI_MakeFillet.hxx>
+#include <ChFiDS_ErrorStatus.hxx>
+#include <TColgp_Array1OfPnt2d.hxx>
+#include <TopExp.hxx>
+#include <TopoDS.hxx>
+#include <TopoDS_Edge.hxx>
+#include <TopTools_IndexedDataMapOfShapeListOfShape.hxx>
+#include <TopTools_IndexedMapOfShape.hxx>
+
+namespace occt {
+
+namespace {
+
+const char* status_name(ChFiDS_ErrorStatus status)
+{
+    switch(status)
+    {
+    case ChFiDS_WalkingFailure:
+        return "walking failure";
+    case ChFiDS_StartsolFailure:
+        return "no start solution";
+    case ChFiDS_TwistedSurface:
+        return "twisted surface";
+    default:
+        return "error";
+    }
+}
+
+// Edge ids are indices in the shape's edge map, same as MeshEdge::edge
+TopoDS_Edge find_edge(const TopTools_IndexedMapOfShape& edge_map, std::uint32_t idx)
+{
+    check_input(idx < static_cast<std::uint32_t>(edge_map.Extent()), "Edge index out of range");
+
+    return TopoDS::Edge(edge_map(static_cast<int>(idx) + 1));
+}
+
+} // namespace
+
+std::unique_ptr<Shape> fillet(const Shape& shape, rust::Slice<const std::uint32_t> edges, rust::Slice<const Tuple2d> law)
+{
+    check_input(!shape.is_null(), "Can't fillet null shape");
+    check_input(edges.size() > 0, "No edges to fillet");
+    check_input(law.size() > 0, "Fillet radius not specified");
+
+    for(std::size_t i = 0; i < law.size(); ++i)
+    {
+        check_input(law[i].y > 0.0, "Fillet radius must be positive");
+        check_input(law[i].x >= 0.0 && law[i].x <= 1.0, "Fillet law parameters must be in [0, 1]");
+        check_input(i == 0 || law[i].x > law[i - 1].x, "Fillet law parameters must be increasing");
+    }
+
+    check_input(law.size() == 1 || (law[0].x == 0.0 && law[law.size() - 1].x == 1.0),
+                "Fillet law must start at parameter 0 and end at 1");
+
+    TopTools_IndexedMapOfShape edge_map;
+
+    TopExp::MapShapes(shape.get(), TopAbs_EDGE, edge_map);
+
+    BRepFilletAPI_MakeFillet mk_fillet(shape.get());
+    const bool linear = law.size() == 2 && law[0].x == 0.0 && law[1].x == 1.0;
+
+    for(std::uint32_t idx : edges)
+    {
+        const TopoDS_Edge edge = find_edge(edge_map, idx);
+
+        if(law.size() == 1)
+        {
+            mk_fillet.Add(law[0].y, edge);
+        }
+        else if(linear)
+        {
+            mk_fillet.Add(law[0].y, law[1].y, edge);
+        }
+        else
+        {
+            TColgp_Array1OfPnt2d params_and_radii(1, static_cast<int>(law.size()));
+
+            for(std::size_t i = 0; i < law.size(); ++i)
+            {
+                params_and_radii.SetValue(static_cast<int>(i) + 1, gp_Pnt2d(law[i].x, law[i].y));
+            }
+
+            mk_fillet.Add(params_and_radii, edge);
+        }
+    }
+
+    mk_fillet.Build();
+
+    if(!mk_fillet.IsDone())
+    {
+        // Name the contours that failed, so callers can retry with other edges or radii
+        std::ostringstream message;
+
+        message << "Fillet failed";
+
+        for(int i = 1; i <= mk_fillet.NbFaultyContours(); ++i)
+        {
+            const int contour = mk_fillet.FaultyContour(i);
+
+            message << (i == 1 ? ": " : "; ") << status_name(mk_fillet.StripeStatus(contour)) << " on edges";
+
+            for(int j = 1; j <= mk_fillet.NbEdges(contour); ++j)
+            {
+                message << " " << edge_map.FindIndex(mk_fillet.Edge(contour, j)) - 1;
+            }
+        }
+
+        if(mk_fillet.NbFaultyVertices() > 0)
+        {
+            message << "; " << mk_fillet.NbFaultyVertices() << " faulty vertices";
+        }
+
+        throw Error::not_done(message.str());
+    }
+
+    return std::make_unique<Shape>(mk_fillet.Shape());
+}
+
+std::unique_ptr<Shape> chamfer(const Shape& shape, rust::Slice<const std::uint32_t> edges, double distance1, double distance2)
+{
+    check_input(!shape.is_null(), "Can't chamfer null shape");
+    check_input(edges.size() > 0, "No edges to chamfer");
+    check_input(distance1 > 0.0 && distance2 > 0.0, "Chamfer distances must be positive");
+
+    TopTools_IndexedMapOfShape edge_map;
+    TopTools_IndexedMapOfShape face_map;
+    TopTools_IndexedDataMapOfShapeListOfShape edge_faces;
+
+    TopExp::MapShapes(shape.get(), TopAbs_EDGE, edge_map);
+    TopExp::MapShapes(shape.get(), TopAbs_FACE, face_map);
+    TopExp::MapShapesAndAncestors(shape.get(), TopAbs_EDGE, TopAbs_FACE, edge_faces);
+
+    BRepFilletAPI_MakeChamfer mk_chamfer(shape.get());
+
+    for(std::uint32_t idx : edges)
+    {
+        const TopoDS_Edge edge = find_edge(edge_map, idx);
+
+        if(distance1 == distance2)
+        {
+            mk_chamfer.Add(distance1, edge);
+        }
+        else
+        {
+            // First distance is measured on the adjacent face first in the face map
+            int first_face = 0;
+
+            for(const TopoDS_Shape& face : edge_faces.FindFromKey(edge))
+            {
+                const int face_idx = face_map.FindIndex(face);
+
+                if(first_face == 0 || face_idx < first_face)
+                {
+                    first_face = face_idx;
+                }
+            }
+
+            check_input(first_face > 0, "Chamfered edge must bound a face");
+
+            mk_chamfer.Add(distance1, distance2, edge, TopoDS::Face(face_map(first_face)));
+        }
+    }
+
+    mk_chamfer.Build();
+
+    if(!mk_chamfer.IsDone())
+    {
+        throw Error::not_done("Chamfer failed, distances may exceed the adjacent faces");
+    }
+
+    return std::make_unique<Shape>(mk_chamfer.Shape());
+}
+
+} // namespace occt
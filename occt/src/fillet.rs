@@ -0,0 +1,75 @@
+use std::convert::TryFrom;
+
+use error::OcctError;
+use occt::ffi;
+use shape::Shape;
+
+/// Radius of a fillet along each selected edge.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilletRadius {
+    Constant(f64),
+    /// Linear from the first to the last vertex of the edge.
+    Linear(f64, f64),
+    /// `(parameter, radius)` pairs along the edge, smoothly interpolated.
+    /// Parameters must increase strictly from 0 to 1.
+    Law(Vec<(f64, f64)>),
+}
+
+/// Rounds `edges` (indices in the shape's edge map, as in `MeshEdge::edge_index`)
+/// with a constant `radius`.
+pub fn fillet(shape: &Shape, edges: &[usize], radius: f64) -> Result<Shape, OcctError> {
+    fillet_with(shape, edges, &FilletRadius::Constant(radius))
+}
+
+/// Rounds `edges` with a constant or evolving radius, failures name the
+/// offending edges.
+pub fn fillet_with(
+    shape: &Shape,
+    edges: &[usize],
+    radius: &FilletRadius,
+) -> Result<Shape, OcctError> {
+    let law = match radius {
+        FilletRadius::Constant(r) => vec![(0.0, *r)],
+        FilletRadius::Linear(r1, r2) => vec![(0.0, *r1), (1.0, *r2)],
+        FilletRadius::Law(law) => law.clone(),
+    };
+    let law: Vec<ffi::Tuple2d> = law
+        .into_iter()
+        .map(|(x, y)| ffi::Tuple2d { x, y })
+        .collect();
+
+    Ok(Shape::from_ffi(ffi::fillet(
+        shape.as_ffi(),
+        &edge_ids(edges),
+        &law,
+    )?))
+}
+
+/// Bevels `edges` by `distance` on both adjacent faces.
+pub fn chamfer(shape: &Shape, edges: &[usize], distance: f64) -> Result<Shape, OcctError> {
+    chamfer_asymmetric(shape, edges, distance, distance)
+}
+
+/// Bevels `edges` by `distance1` on the adjacent face with the lower index
+/// in the shape's face map and `distance2` on the other.
+pub fn chamfer_asymmetric(
+    shape: &Shape,
+    edges: &[usize],
+    distance1: f64,
+    distance2: f64,
+) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::chamfer(
+        shape.as_ffi(),
+        &edge_ids(edges),
+        distance1,
+        distance2,
+    )?))
+}
+
+fn edge_ids(edges: &[usize]) -> Vec<u32> {
+    // Out of range ids are rejected on the C++ side
+    edges
+        .iter()
+        .map(|&e| u32::try_from(e).unwrap_or(u32::MAX))
+        .collect()
+}
@@ -0,0 +1,226 @@
+#include "occt/src/occt_p.h"
+
+#include <cmath>
+
+#include <BRep_Tool.hxx>
+#include <BRepAlgoAPI_Fuse.hxx>
+#include <BRepBuilderAPI_MakeEdge.hxx>
+#include <BRepBuilderAPI_MakeFace.hxx>
+#include <BRepBuilderAPI_MakeWire.hxx>
+#include <BRepBuilderAPI_Transform.hxx>
+#include <BRepFilletAPI_MakeFillet.hxx>
+#include <BRepLib.hxx>
+#include <BRepOffsetAPI_MakeThickSolid.hxx>
+#include <BRepOffsetAPI_ThruSections.hxx>
+#include <BRepPrimAPI_MakeCylinder.hxx>
+#include <BRepPrimAPI_MakePrism.hxx>
+#include <GC_MakeArcOfCircle.hxx>
+#include <GC_MakeSegment.hxx>
+#include <GCE2d_MakeSegment.hxx>
+#include <Geom2d_Ellipse.hxx>
+#include <Geom2d_TrimmedCurve.hxx>
+#include <Geom_CylindricalSurface.hxx>
+#include <Geom_Plane.hxx>
+#include <Geom_TrimmedCurve.hxx>
+#include <gp_Ax1.hxx>
+#include <gp_Ax2d.hxx>
+#include <gp_Dir2d.hxx>
+#include <gp_Pnt.hxx>
+#include <gp_Pnt2d.hxx>
+#include <TopExp_Explorer.hxx>
+#include <TopoDS.hxx>
+#include <TopoDS_Edge.hxx>
+#include <TopoDS_Face.hxx>
+#include <TopoDS_Wire.hxx>
+
+namespace occt {
+
+namespace {
+
+TopoDS_Shape build_flask(const FlaskParams& params)
+{
+    const double width = params.width;
+    const double thickness = params.thickness;
+    const double height = params.height;
+    const double neck_radius = thickness / 4.0;
+    const double neck_height = height / 10.0;
+    const bool threaded = params.thread;
+
+    check_input(width > 0.0, "Flask width must be positive");
+    check_input(thickness > 0.0, "Flask thickness must be positive");
+    check_input(height > 0.0, "Flask height must be positive");
+    // Profile arcs and neck are sized from the thickness, they must stay inside the width
+    check_input(thickness <= width, "Flask thickness must not exceed its width");
+    // The neck and the flat ends of the body are the narrowest parts, both are neck_radius
+    // from the center, a wall that thick would close the flask
+    check_input(params.wall_thickness >= 0.0 && params.wall_thickness < neck_radius, "Flask wall thickness must be in [0, thickness / 4)");
+
+    if(threaded)
+    {
+        check_input(params.thread_turns > 0.0, "Thread turns must be positive");
+        check_input(params.thread_pitch > 0.0, "Thread pitch must be positive");
+        check_input(params.thread_depth > 0.0, "Thread depth must be positive");
+        // Thread profile is 0.4 pitch tall at both ends of the helix
+        check_input(params.thread_pitch * (params.thread_turns + 0.8) <= neck_height, "Thread does not fit on the neck");
+        // Inner thread surface sits slightly inside the neck wall
+        check_input(params.wall_thickness == 0.0 || params.wall_thickness > neck_radius * 0.01, "Flask wall is too thin for the thread");
+    }
+
+    TopoDS_Shape body;
+
+    // Extrude
+    {
+        gp_Pnt pts[5] = {
+            {-width * 0.5, 0.0,               0.0},
+            {-width * 0.5, 0.0, -thickness * 0.25},
+            {         0.0, 0.0,  -thickness * 0.5},
+            { width * 0.5, 0.0, -thickness * 0.25},
+            { width * 0.5, 0.0,               0.0}
+        };
+
+        Handle(Geom_TrimmedCurve) arc = GC_MakeArcOfCircle(pts[1], pts[2], pts[3]);
+        Handle(Geom_TrimmedCurve) seg1 = GC_MakeSegment(pts[0], pts[1]);
+        Handle(Geom_TrimmedCurve) seg2 = GC_MakeSegment(pts[3], pts[4]);
+
+        TopoDS_Wire wire1 = BRepBuilderAPI_MakeWire(BRepBuilderAPI_MakeEdge(seg1),
+            BRepBuilderAPI_MakeEdge(arc),
+            BRepBuilderAPI_MakeEdge(seg2));
+
+        gp_Trsf xf;
+
+        xf.SetMirror(gp::OX());
+
+        TopoDS_Wire wire2 = TopoDS::Wire(BRepBuilderAPI_Transform(wire1, xf).Shape());
+
+        BRepBuilderAPI_MakeWire mk_wire;
+
+        mk_wire.Add(wire1);
+        mk_wire.Add(wire2);
+
+        BRepPrimAPI_MakePrism mk_prism(BRepBuilderAPI_MakeFace(mk_wire.Wire()), gp_Vec(0.0, height, 0.0));
+
+        body = check_done(mk_prism, "Flask body extrusion failed");
+    }
+
+    // Fillet cask
+    {
+        BRepFilletAPI_MakeFillet mk_fillet(body);
+
+        for(TopExp_Explorer edge_i(body, TopAbs_EDGE); edge_i.More(); edge_i.Next())
+        {
+            mk_fillet.Add(thickness / 12.0, TopoDS::Edge(edge_i.Current()));
+        }
+
+        body = check_done(mk_fillet, "Flask body fillet failed");
+    }
+
+    const gp_Ax2 neck_ax2(gp_Pnt(0.0, height, 0.0), gp::DY());
+
+    // Fuse with neck
+    {
+        BRepAlgoAPI_Fuse mk_fuse(body, BRepPrimAPI_MakeCylinder(neck_ax2, neck_radius, neck_height));
+
+        if(mk_fuse.HasErrors())
+        {
+            throw Error::not_done("Flask neck fuse failed");
+        }
+
+        body = mk_fuse.Shape();
+    }
+
+    // Hollow it out through the top face of the neck
+    if(params.wall_thickness > 0.0)
+    {
+        TopoDS_Face top_face;
+        double top_y = -1.0;
+
+        for(TopExp_Explorer face_i(body, TopAbs_FACE); face_i.More(); face_i.Next())
+        {
+            const TopoDS_Face face = TopoDS::Face(face_i.Current());
+            Handle(Geom_Plane) plane = Handle(Geom_Plane)::DownCast(BRep_Tool::Surface(face));
+
+            if(!plane.IsNull() && plane->Location().Y() > top_y)
+            {
+                top_y = plane->Location().Y();
+                top_face = face;
+            }
+        }
+
+        if(top_face.IsNull())
+        {
+            throw Error::not_done("Flask has no neck face to open");
+        }
+
+        TopTools_ListOfShape faces_to_remove;
+
+        faces_to_remove.Append(top_face);
+
+        BRepOffsetAPI_MakeThickSolid mk_thick;
+
+        mk_thick.MakeThickSolidByJoin(body, faces_to_remove, -params.wall_thickness, 1.e-3);
+
+        body = check_done(mk_thick, "Flask hollowing failed");
+    }
+
+    // Thread as a loft between two helical profiles laid out on cylinders
+    // around the neck, see the OCCT bottle tutorial
+    if(threaded)
+    {
+        Handle(Geom_CylindricalSurface) cyl1 = new Geom_CylindricalSurface(neck_ax2, neck_radius * 0.99);
+        Handle(Geom_CylindricalSurface) cyl2 = new Geom_CylindricalSurface(neck_ax2, neck_radius + params.thread_depth);
+
+        // In cylinder UV space (angle, height) a helix is a straight line rising
+        // one pitch per 2 * PI, the ellipse spans the requested number of turns
+        const gp_Dir2d dir(2.0 * M_PI, params.thread_pitch);
+        const gp_Ax2d ax2d(gp_Pnt2d(2.0 * M_PI, neck_height / 2.0), dir);
+        const double major = 0.5 * params.thread_turns * std::hypot(2.0 * M_PI, params.thread_pitch);
+        const double minor = params.thread_pitch * 0.4;
+
+        Handle(Geom2d_Ellipse) ellipse1 = new Geom2d_Ellipse(ax2d, major, minor);
+        Handle(Geom2d_Ellipse) ellipse2 = new Geom2d_Ellipse(ax2d, major, minor / 4.0);
+        Handle(Geom2d_TrimmedCurve) arc1 = new Geom2d_TrimmedCurve(ellipse1, 0.0, M_PI);
+        Handle(Geom2d_TrimmedCurve) arc2 = new Geom2d_TrimmedCurve(ellipse2, 0.0, M_PI);
+        Handle(Geom2d_TrimmedCurve) segment = GCE2d_MakeSegment(ellipse1->Value(0.0), ellipse1->Value(M_PI));
+
+        TopoDS_Wire wire1 = BRepBuilderAPI_MakeWire(BRepBuilderAPI_MakeEdge(arc1, cyl1),
+            BRepBuilderAPI_MakeEdge(segment, cyl1));
+        TopoDS_Wire wire2 = BRepBuilderAPI_MakeWire(BRepBuilderAPI_MakeEdge(arc2, cyl2),
+            BRepBuilderAPI_MakeEdge(segment, cyl2));
+
+        BRepLib::BuildCurves3d(wire1);
+        BRepLib::BuildCurves3d(wire2);
+
+        BRepOffsetAPI_ThruSections mk_thread(Standard_True);
+
+        mk_thread.AddWire(wire1);
+        mk_thread.AddWire(wire2);
+        mk_thread.CheckCompatibility(Standard_False);
+
+        const TopoDS_Shape thread = check_done(mk_thread, "Flask thread construction failed");
+
+        BRepAlgoAPI_Fuse mk_fuse(body, thread);
+
+        if(mk_fuse.HasErrors())
+        {
+            throw Error::not_done("Flask thread fuse failed");
+        }
+
+        body = mk_fuse.Shape();
+    }
+
+    return body;
+}
+
+} // namespace
+
+std::unique_ptr<MeshBlob> make_flask(const FlaskParams& params, const MeshParams& mesh_params)
+{
+    return mesh_body(build_flask(params), mesh_params);
+}
+
+std::unique_ptr<Shape> make_flask_shape(const FlaskParams& params)
+{
+    return std::make_unique<Shape>(build_flask(params));
+}
+
+} // namespace occt
@@ -0,0 +1,95 @@
+use error::OcctError;
+use mesh::{MeshBlob, MeshParams};
+use occt::ffi;
+use shape::Shape;
+
+/// Helical thread around the flask neck.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThreadParams {
+    /// Rise per turn.
+    pub pitch: f64,
+    /// Radial height above the neck surface.
+    pub depth: f64,
+    pub turns: f64,
+}
+
+/// Flask (the OCCT tutorial bottle) dimensions, body height excludes the neck.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlaskParams {
+    pub width: f64,
+    pub thickness: f64,
+    pub height: f64,
+    /// Wall thickness of the hollow body, zero for a solid flask, below
+    /// `thickness / 4` so the neck stays open.
+    pub wall_thickness: f64,
+    pub thread: Option<ThreadParams>,
+}
+
+impl FlaskParams {
+    /// Solid flask without thread.
+    pub fn new(width: f64, thickness: f64, height: f64) -> Self {
+        Self {
+            width,
+            thickness,
+            height,
+            wall_thickness: 0.0,
+            thread: None,
+        }
+    }
+
+    /// Hollow threaded bottle with the proportions of the OCCT tutorial.
+    pub fn classic(width: f64, thickness: f64, height: f64) -> Self {
+        let neck_height = height / 10.0;
+
+        Self {
+            wall_thickness: thickness / 50.0,
+            thread: Some(ThreadParams {
+                pitch: neck_height / 4.0,
+                depth: thickness / 4.0 * 0.05,
+                turns: 2.0,
+            }),
+            ..Self::new(width, thickness, height)
+        }
+    }
+
+    pub fn with_wall_thickness(mut self, wall_thickness: f64) -> Self {
+        self.wall_thickness = wall_thickness;
+        self
+    }
+
+    /// `None` builds a plain neck, a thread must have positive turns.
+    pub fn with_thread(mut self, thread: Option<ThreadParams>) -> Self {
+        self.thread = thread;
+        self
+    }
+
+    fn to_ffi(self) -> ffi::FlaskParams {
+        let thread = self.thread.unwrap_or(ThreadParams {
+            pitch: 0.0,
+            depth: 0.0,
+            turns: 0.0,
+        });
+
+        ffi::FlaskParams {
+            width: self.width,
+            thickness: self.thickness,
+            height: self.height,
+            wall_thickness: self.wall_thickness,
+            thread: self.thread.is_some(),
+            thread_pitch: thread.pitch,
+            thread_depth: thread.depth,
+            thread_turns: thread.turns,
+        }
+    }
+}
+
+pub fn make_flask(params: &FlaskParams) -> Result<MeshBlob, OcctError> {
+    Ok(ffi::make_flask(
+        &params.to_ffi(),
+        &MeshParams::default().to_ffi(),
+    )?)
+}
+
+pub fn make_flask_shape(params: &FlaskParams) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::make_flask_shape(&params.to_ffi())?))
+}
@@ -0,0 +1,310 @@
+#include "occt/src/occt_p.h"
+
+#include <BRep_Tool.hxx>
+#include <BRepAdaptor_Curve.hxx>
+#include <BRepAdaptor_Surface.hxx>
+#include <BRepLProp_CLProps.hxx>
+#include <BRepLProp_SLProps.hxx>
+#include <BRepTools.hxx>
+#include <GCPnts_AbscissaPoint.hxx>
+#include <gp_Ax1.hxx>
+#include <gp_Ax3.hxx>
+#include <Precision.hxx>
+#include <TopoDS.hxx>
+
+namespace occt {
+
+namespace {
+
+Axes3d from_ax2(const gp_Ax2& ax)
+{
+    return {from_xyz(ax.Location().XYZ()), from_xyz(ax.Direction().XYZ()), from_xyz(ax.XDirection().XYZ())};
+}
+
+Axes3d from_ax3(const gp_Ax3& ax)
+{
+    return from_ax2(ax.Ax2());
+}
+
+Axes3d from_ax1(const gp_Ax1& ax)
+{
+    return {from_xyz(ax.Location().XYZ()), from_xyz(ax.Direction().XYZ()), {0.0, 0.0, 0.0}};
+}
+
+const TopoDS_Face& to_face(const Shape& face)
+{
+    check_input(!face.is_null() && face.get().ShapeType() == TopAbs_FACE, "Shape is not a face");
+
+    return TopoDS::Face(face.get());
+}
+
+const TopoDS_Edge& to_edge(const Shape& edge)
+{
+    check_input(!edge.is_null() && edge.get().ShapeType() == TopAbs_EDGE, "Shape is not an edge");
+    check_input(!BRep_Tool::Degenerated(TopoDS::Edge(edge.get())), "Degenerated edge has no curve");
+
+    return TopoDS::Edge(edge.get());
+}
+
+Tuple3d negated(const Tuple3d& v)
+{
+    return {-v.x, -v.y, -v.z};
+}
+
+} // namespace
+
+SurfaceInfo face_surface(const Shape& face)
+{
+    // Adaptor applies the face location, parameters are in model space
+    const BRepAdaptor_Surface surface(to_face(face));
+    SurfaceInfo info;
+
+    info.kind = SurfaceKind::Other;
+    info.axes = from_ax1(gp_Ax1());
+    info.radius = 0.0;
+    info.radius2 = 0.0;
+    info.angle = 0.0;
+
+    switch(surface.GetType())
+    {
+    case GeomAbs_Plane:
+        info.kind = SurfaceKind::Plane;
+        info.axes = from_ax3(surface.Plane().Position());
+
+        // Report the normal the face actually points to
+        if(face.get().Orientation() == TopAbs_REVERSED)
+        {
+            info.axes.dir = negated(info.axes.dir);
+        }
+
+        break;
+    case GeomAbs_Cylinder:
+        info.kind = SurfaceKind::Cylinder;
+        info.axes = from_ax3(surface.Cylinder().Position());
+        info.radius = surface.Cylinder().Radius();
+        break;
+    case GeomAbs_Cone:
+        info.kind = SurfaceKind::Cone;
+        info.axes = from_ax3(surface.Cone().Position());
+        info.radius = surface.Cone().RefRadius();
+        info.angle = surface.Cone().SemiAngle();
+        break;
+    case GeomAbs_Sphere:
+        info.kind = SurfaceKind::Sphere;
+        info.axes = from_ax3(surface.Sphere().Position());
+        info.radius = surface.Sphere().Radius();
+        break;
+    case GeomAbs_Torus:
+        info.kind = SurfaceKind::Torus;
+        info.axes = from_ax3(surface.Torus().Position());
+        info.radius = surface.Torus().MajorRadius();
+        info.radius2 = surface.Torus().MinorRadius();
+        break;
+    case GeomAbs_SurfaceOfRevolution:
+        info.kind = SurfaceKind::Revolution;
+        info.axes = from_ax1(surface.AxeOfRevolution());
+        break;
+    case GeomAbs_SurfaceOfExtrusion:
+        info.kind = SurfaceKind::Extrusion;
+        info.axes.dir = from_xyz(surface.Direction().XYZ());
+        break;
+    case GeomAbs_BezierSurface:
+        info.kind = SurfaceKind::Bezier;
+        break;
+    case GeomAbs_BSplineSurface:
+        info.kind = SurfaceKind::BSpline;
+        break;
+    case GeomAbs_OffsetSurface:
+        info.kind = SurfaceKind::Offset;
+        break;
+    default:
+        break;
+    }
+
+    return info;
+}
+
+CurveInfo edge_curve(const Shape& edge)
+{
+    const BRepAdaptor_Curve curve(to_edge(edge));
+    CurveInfo info;
+
+    info.kind = CurveKind::Other;
+    info.axes = from_ax1(gp_Ax1());
+    info.radius = 0.0;
+    info.radius2 = 0.0;
+
+    switch(curve.GetType())
+    {
+    case GeomAbs_Line:
+        info.kind = CurveKind::Line;
+        info.axes = from_ax1(curve.Line().Position());
+        break;
+    case GeomAbs_Circle:
+        info.kind = CurveKind::Circle;
+        info.axes = from_ax2(curve.Circle().Position());
+        info.radius = curve.Circle().Radius();
+        break;
+    case GeomAbs_Ellipse:
+        info.kind = CurveKind::Ellipse;
+        info.axes = from_ax2(curve.Ellipse().Position());
+        info.radius = curve.Ellipse().MajorRadius();
+        info.radius2 = curve.Ellipse().MinorRadius();
+        break;
+    case GeomAbs_Hyperbola:
+        info.kind = CurveKind::Hyperbola;
+        info.axes = from_ax2(curve.Hyperbola().Position());
+        info.radius = curve.Hyperbola().MajorRadius();
+        info.radius2 = curve.Hyperbola().MinorRadius();
+        break;
+    case GeomAbs_Parabola:
+        info.kind = CurveKind::Parabola;
+        info.axes = from_ax2(curve.Parabola().Position());
+        info.radius = curve.Parabola().Focal();
+        break;
+    case GeomAbs_BezierCurve:
+        info.kind = CurveKind::Bezier;
+        break;
+    case GeomAbs_BSplineCurve:
+        info.kind = CurveKind::BSpline;
+        break;
+    case GeomAbs_OffsetCurve:
+        info.kind = CurveKind::Offset;
+        break;
+    default:
+        break;
+    }
+
+    return info;
+}
+
+UvBounds face_uv_bounds(const Shape& face)
+{
+    UvBounds bounds;
+
+    BRepTools::UVBounds(to_face(face), bounds.u_min, bounds.u_max, bounds.v_min, bounds.v_max);
+
+    return bounds;
+}
+
+SurfacePoint eval_face(const Shape& face, double u, double v)
+{
+    const TopoDS_Face& f = to_face(face);
+    const BRepAdaptor_Surface surface(f);
+    BRepLProp_SLProps props(surface, u, v, 2, Precision::Confusion());
+    const bool reversed = f.Orientation() == TopAbs_REVERSED;
+    SurfacePoint point;
+
+    point.point = from_xyz(props.Value().XYZ());
+    point.d1u = from_xyz(props.D1U().XYZ());
+    point.d1v = from_xyz(props.D1V().XYZ());
+    point.d2u = from_xyz(props.D2U().XYZ());
+    point.d2v = from_xyz(props.D2V().XYZ());
+    point.d2uv = from_xyz(props.DUV().XYZ());
+    point.normal = {0.0, 0.0, 0.0};
+    point.normal_defined = props.IsNormalDefined();
+    point.min_curvature = 0.0;
+    point.max_curvature = 0.0;
+    point.min_dir = {0.0, 0.0, 0.0};
+    point.max_dir = {0.0, 0.0, 0.0};
+    point.mean_curvature = 0.0;
+    point.gaussian_curvature = 0.0;
+    point.curvature_defined = false;
+
+    if(!point.normal_defined)
+    {
+        return point;
+    }
+
+    point.normal = from_xyz(props.Normal().XYZ());
+    point.curvature_defined = props.IsCurvatureDefined();
+
+    if(point.curvature_defined)
+    {
+        gp_Dir max_dir;
+        gp_Dir min_dir;
+
+        props.CurvatureDirections(max_dir, min_dir);
+
+        point.min_curvature = props.MinCurvature();
+        point.max_curvature = props.MaxCurvature();
+        point.min_dir = from_xyz(min_dir.XYZ());
+        point.max_dir = from_xyz(max_dir.XYZ());
+        point.mean_curvature = props.MeanCurvature();
+        point.gaussian_curvature = props.GaussianCurvature();
+    }
+
+    // Curvatures are signed against the normal, flipping it swaps min and max
+    if(reversed)
+    {
+        point.normal = negated(point.normal);
+
+        const double min_curvature = point.min_curvature;
+        const Tuple3d min_dir = point.min_dir;
+
+        point.min_curvature = -point.max_curvature;
+        point.max_curvature = -min_curvature;
+        point.min_dir = point.max_dir;
+        point.max_dir = min_dir;
+        point.mean_curvature = -point.mean_curvature;
+    }
+
+    return point;
+}
+
+Tuple2d edge_range(const Shape& edge)
+{
+    Tuple2d range;
+
+    BRep_Tool::Range(to_edge(edge), range.x, range.y);
+
+    return range;
+}
+
+CurvePoint eval_edge(const Shape& edge, double t)
+{
+    const BRepAdaptor_Curve curve(to_edge(edge));
+    BRepLProp_CLProps props(curve, t, 2, Precision::Confusion());
+    CurvePoint point;
+
+    point.point = from_xyz(props.Value().XYZ());
+    point.d1 = from_xyz(props.D1().XYZ());
+    point.d2 = from_xyz(props.D2().XYZ());
+    point.tangent = {0.0, 0.0, 0.0};
+    point.tangent_defined = props.IsTangentDefined();
+    point.curvature = 0.0;
+    point.normal = {0.0, 0.0, 0.0};
+    point.normal_defined = false;
+
+    if(!point.tangent_defined)
+    {
+        return point;
+    }
+
+    gp_Dir tangent;
+
+    props.Tangent(tangent);
+    point.tangent = from_xyz(tangent.XYZ());
+    point.curvature = props.Curvature();
+
+    // Principal normal is undefined on straight portions
+    if(point.curvature > Precision::Confusion())
+    {
+        gp_Dir normal;
+
+        props.Normal(normal);
+        point.normal = from_xyz(normal.XYZ());
+        point.normal_defined = true;
+    }
+
+    return point;
+}
+
+double edge_length(const Shape& edge)
+{
+    const BRepAdaptor_Curve curve(to_edge(edge));
+
+    return GCPnts_AbscissaPoint::Length(curve);
+}
+
+} // namespace occt
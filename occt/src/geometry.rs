@@ -0,0 +1,284 @@
+use glam::DVec3;
+
+use error::OcctError;
+use occt::ffi;
+use placement::Placement;
+use shape::Shape;
+
+/// Underlying surface of a face (`GeomAbs_SurfaceType`) with its defining parameters.
+///
+/// Placements give the surface axis system in model space, `dir` is the axis
+/// of revolution for cylinders, cones, spheres and tori.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Surface {
+    /// `placement.dir` is the face normal, following the face orientation.
+    Plane {
+        placement: Placement,
+    },
+    Cylinder {
+        placement: Placement,
+        radius: f64,
+    },
+    /// `radius` is measured at the placement origin.
+    Cone {
+        placement: Placement,
+        radius: f64,
+        semi_angle: f64,
+    },
+    Sphere {
+        placement: Placement,
+        radius: f64,
+    },
+    Torus {
+        placement: Placement,
+        major_radius: f64,
+        minor_radius: f64,
+    },
+    Revolution {
+        origin: DVec3,
+        axis: DVec3,
+    },
+    Extrusion {
+        direction: DVec3,
+    },
+    Bezier,
+    BSpline,
+    Offset,
+    Other,
+}
+
+/// Underlying 3D curve of an edge (`GeomAbs_CurveType`) with its defining parameters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Curve {
+    Line {
+        origin: DVec3,
+        direction: DVec3,
+    },
+    /// `placement.dir` is the normal of the circle plane.
+    Circle {
+        placement: Placement,
+        radius: f64,
+    },
+    /// Major axis along `placement.x_dir`.
+    Ellipse {
+        placement: Placement,
+        major_radius: f64,
+        minor_radius: f64,
+    },
+    Hyperbola {
+        placement: Placement,
+        major_radius: f64,
+        minor_radius: f64,
+    },
+    Parabola {
+        placement: Placement,
+        focal: f64,
+    },
+    Bezier,
+    BSpline,
+    Offset,
+    Other,
+}
+
+impl Shape {
+    /// Classifies a face, fails for other shape types.
+    pub fn surface(&self) -> Result<Surface, OcctError> {
+        let info = ffi::face_surface(self.as_ffi())?;
+        let placement = Placement::from_ffi(info.axes);
+
+        Ok(match info.kind {
+            ffi::SurfaceKind::Plane => Surface::Plane { placement },
+            ffi::SurfaceKind::Cylinder => Surface::Cylinder {
+                placement,
+                radius: info.radius,
+            },
+            ffi::SurfaceKind::Cone => Surface::Cone {
+                placement,
+                radius: info.radius,
+                semi_angle: info.angle,
+            },
+            ffi::SurfaceKind::Sphere => Surface::Sphere {
+                placement,
+                radius: info.radius,
+            },
+            ffi::SurfaceKind::Torus => Surface::Torus {
+                placement,
+                major_radius: info.radius,
+                minor_radius: info.radius2,
+            },
+            ffi::SurfaceKind::Revolution => Surface::Revolution {
+                origin: placement.origin,
+                axis: placement.dir,
+            },
+            ffi::SurfaceKind::Extrusion => Surface::Extrusion {
+                direction: placement.dir,
+            },
+            ffi::SurfaceKind::Bezier => Surface::Bezier,
+            ffi::SurfaceKind::BSpline => Surface::BSpline,
+            ffi::SurfaceKind::Offset => Surface::Offset,
+            _ => Surface::Other,
+        })
+    }
+
+    /// Classifies an edge, fails for other shape types and degenerated edges.
+    pub fn curve(&self) -> Result<Curve, OcctError> {
+        let info = ffi::edge_curve(self.as_ffi())?;
+        let placement = Placement::from_ffi(info.axes);
+
+        Ok(match info.kind {
+            ffi::CurveKind::Line => Curve::Line {
+                origin: placement.origin,
+                direction: placement.dir,
+            },
+            ffi::CurveKind::Circle => Curve::Circle {
+                placement,
+                radius: info.radius,
+            },
+            ffi::CurveKind::Ellipse => Curve::Ellipse {
+                placement,
+                major_radius: info.radius,
+                minor_radius: info.radius2,
+            },
+            ffi::CurveKind::Hyperbola => Curve::Hyperbola {
+                placement,
+                major_radius: info.radius,
+                minor_radius: info.radius2,
+            },
+            ffi::CurveKind::Parabola => Curve::Parabola {
+                placement,
+                focal: info.radius,
+            },
+            ffi::CurveKind::Bezier => Curve::Bezier,
+            ffi::CurveKind::BSpline => Curve::BSpline,
+            ffi::CurveKind::Offset => Curve::Offset,
+            _ => Curve::Other,
+        })
+    }
+}
+
+/// Parameter bounds of a face, taken from its wires.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UvBounds {
+    pub u_min: f64,
+    pub u_max: f64,
+    pub v_min: f64,
+    pub v_max: f64,
+}
+
+/// Principal curvatures at a surface point, signed against the face normal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SurfaceCurvature {
+    pub min: f64,
+    pub max: f64,
+    pub min_dir: DVec3,
+    pub max_dir: DVec3,
+    pub mean: f64,
+    pub gaussian: f64,
+}
+
+/// Local properties of a face at `(u, v)`, see `BRepLProp_SLProps`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SurfacePoint {
+    pub point: DVec3,
+    pub d1u: DVec3,
+    pub d1v: DVec3,
+    pub d2u: DVec3,
+    pub d2v: DVec3,
+    pub d2uv: DVec3,
+    /// Unit normal following the face orientation, `None` at singular points.
+    pub normal: Option<DVec3>,
+    pub curvature: Option<SurfaceCurvature>,
+}
+
+/// Local properties of an edge at parameter `t`, see `BRepLProp_CLProps`.
+///
+/// Derivatives follow the curve parameterization, reversed edges run against it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CurvePoint {
+    pub point: DVec3,
+    pub d1: DVec3,
+    pub d2: DVec3,
+    pub tangent: Option<DVec3>,
+    pub curvature: f64,
+    /// Principal normal, `None` where the curve is straight.
+    pub normal: Option<DVec3>,
+}
+
+impl Shape {
+    pub fn uv_bounds(&self) -> Result<UvBounds, OcctError> {
+        let bounds = ffi::face_uv_bounds(self.as_ffi())?;
+
+        Ok(UvBounds {
+            u_min: bounds.u_min,
+            u_max: bounds.u_max,
+            v_min: bounds.v_min,
+            v_max: bounds.v_max,
+        })
+    }
+
+    /// Evaluates a face on its exact surface, in model space.
+    pub fn surface_point(&self, u: f64, v: f64) -> Result<SurfacePoint, OcctError> {
+        let raw = ffi::eval_face(self.as_ffi(), u, v)?;
+        let curvature = if raw.curvature_defined {
+            Some(SurfaceCurvature {
+                min: raw.min_curvature,
+                max: raw.max_curvature,
+                min_dir: raw.min_dir.into(),
+                max_dir: raw.max_dir.into(),
+                mean: raw.mean_curvature,
+                gaussian: raw.gaussian_curvature,
+            })
+        } else {
+            None
+        };
+
+        Ok(SurfacePoint {
+            point: raw.point.into(),
+            d1u: raw.d1u.into(),
+            d1v: raw.d1v.into(),
+            d2u: raw.d2u.into(),
+            d2v: raw.d2v.into(),
+            d2uv: raw.d2uv.into(),
+            normal: if raw.normal_defined {
+                Some(raw.normal.into())
+            } else {
+                None
+            },
+            curvature,
+        })
+    }
+
+    /// Parameter range `(first, last)` of an edge.
+    pub fn parameter_range(&self) -> Result<(f64, f64), OcctError> {
+        let range = ffi::edge_range(self.as_ffi())?;
+
+        Ok((range.x, range.y))
+    }
+
+    /// Evaluates an edge on its exact 3D curve, in model space.
+    pub fn curve_point(&self, t: f64) -> Result<CurvePoint, OcctError> {
+        let raw = ffi::eval_edge(self.as_ffi(), t)?;
+
+        Ok(CurvePoint {
+            point: raw.point.into(),
+            d1: raw.d1.into(),
+            d2: raw.d2.into(),
+            tangent: if raw.tangent_defined {
+                Some(raw.tangent.into())
+            } else {
+                None
+            },
+            curvature: raw.curvature,
+            normal: if raw.normal_defined {
+                Some(raw.normal.into())
+            } else {
+                None
+            },
+        })
+    }
+
+    /// Arc length of an edge.
+    pub fn length(&self) -> Result<f64, OcctError> {
+        Ok(ffi::edge_length(self.as_ffi())?)
+    }
+}
@@ -0,0 +1,722 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use glam::{DAffine3, DMat3, Vec3};
+
+use error::{ErrorKind, OcctError};
+use mesh::{Color, MeshView};
+use occt::ffi;
+
+const DEFAULT_COLOR: Color = [0.8, 0.8, 0.8, 1.0];
+
+const GLB_MAGIC: u32 = 0x4654_6C67;
+const GLB_JSON_CHUNK: u32 = 0x4E4F_534A;
+const GLB_BIN_CHUNK: u32 = 0x004E_4942;
+
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GltfFormat {
+    /// Single `.glb` file, JSON and binary chunks.
+    #[default]
+    Binary,
+    /// `.gltf` JSON with the buffer embedded as a base64 data URI.
+    Embedded,
+}
+
+/// How the triangles of a mesh are grouped into glTF primitives.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GltfPrimitives {
+    /// One primitive per B-rep face, so faces can carry their own color.
+    #[default]
+    PerFace,
+    /// One primitive per part.
+    PerPart,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GltfOptions {
+    pub format: GltfFormat,
+    pub primitives: GltfPrimitives,
+}
+
+#[derive(Clone)]
+pub struct GltfMesh<'a> {
+    pub mesh: &'a ffi::MeshBlob,
+    pub color: Color,
+    /// Overrides keyed by face index, used with `GltfPrimitives::PerFace`.
+    pub face_colors: HashMap<usize, Color>,
+}
+
+/// Node of the exported scene, parts are leaves and groups form the assembly.
+#[derive(Clone)]
+pub struct GltfNode<'a> {
+    pub name: String,
+    /// Placement relative to the parent node.
+    pub transform: DAffine3,
+    pub mesh: Option<GltfMesh<'a>>,
+    pub children: Vec<GltfNode<'a>>,
+}
+
+impl<'a> GltfNode<'a> {
+    pub fn group(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            transform: DAffine3::IDENTITY,
+            mesh: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn part(name: &str, mesh: &'a ffi::MeshBlob) -> Self {
+        Self {
+            mesh: Some(GltfMesh {
+                mesh,
+                color: DEFAULT_COLOR,
+                face_colors: HashMap::new(),
+            }),
+            ..Self::group(name)
+        }
+    }
+
+    pub fn with_transform(mut self, transform: DAffine3) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Base color of the part, ignored for groups.
+    pub fn with_color(mut self, color: Color) -> Self {
+        if let Some(mesh) = &mut self.mesh {
+            mesh.color = color;
+        }
+        self
+    }
+
+    pub fn with_face_color(mut self, face: usize, color: Color) -> Self {
+        if let Some(mesh) = &mut self.mesh {
+            mesh.face_colors.insert(face, color);
+        }
+        self
+    }
+
+    pub fn with_child(mut self, child: GltfNode<'a>) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+/// Writes `nodes` as the roots of a single glTF 2.0 scene. Node transforms
+/// must be made of translation, rotation and scale, without shear.
+pub fn write_gltf<P: AsRef<Path>>(
+    nodes: &[GltfNode],
+    path: P,
+    options: &GltfOptions,
+) -> Result<(), OcctError> {
+    let mut out = BufWriter::new(File::create(path)?);
+
+    write_gltf_to(nodes, &mut out, options)?;
+
+    Ok(out.flush()?)
+}
+
+pub fn write_gltf_to<W: Write>(
+    nodes: &[GltfNode],
+    mut out: W,
+    options: &GltfOptions,
+) -> Result<(), OcctError> {
+    let mut builder = Builder::default();
+    let roots = nodes
+        .iter()
+        .map(|node| builder.add_node(node, options))
+        .collect::<Result<Vec<usize>, OcctError>>()?;
+
+    match options.format {
+        GltfFormat::Binary => {
+            let json = builder.json(&roots, None).into_bytes();
+
+            write_glb(&mut out, json, builder.bin)?;
+        }
+        GltfFormat::Embedded => {
+            let uri = format!(
+                "data:application/octet-stream;base64,{}",
+                base64(&builder.bin)
+            );
+
+            out.write_all(builder.json(&roots, Some(&uri)).as_bytes())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// GLB container, chunks are padded to 4 bytes as the spec requires.
+fn write_glb<W: Write>(out: &mut W, mut json: Vec<u8>, mut bin: Vec<u8>) -> Result<(), OcctError> {
+    pad(&mut json, b' ');
+    pad(&mut bin, 0);
+
+    let bin_chunk = if bin.is_empty() { 0 } else { 8 + bin.len() };
+    let total = 12 + 8 + json.len() + bin_chunk;
+
+    out.write_all(&GLB_MAGIC.to_le_bytes())?;
+    out.write_all(&2u32.to_le_bytes())?;
+    out.write_all(&(total as u32).to_le_bytes())?;
+    out.write_all(&(json.len() as u32).to_le_bytes())?;
+    out.write_all(&GLB_JSON_CHUNK.to_le_bytes())?;
+    out.write_all(&json)?;
+
+    if !bin.is_empty() {
+        out.write_all(&(bin.len() as u32).to_le_bytes())?;
+        out.write_all(&GLB_BIN_CHUNK.to_le_bytes())?;
+        out.write_all(&bin)?;
+    }
+
+    Ok(())
+}
+
+/// Buffers of a mesh, written once and shared by every part using it.
+#[derive(Clone, Copy)]
+struct Geometry {
+    position: usize,
+    normal: usize,
+    indices: usize,
+}
+
+#[derive(Default)]
+struct Builder {
+    bin: Vec<u8>,
+    /// Keyed by `MeshBlob` address, parts instancing a blob share its buffers.
+    geometries: HashMap<usize, Geometry>,
+    views: Vec<String>,
+    accessors: Vec<String>,
+    materials: Vec<String>,
+    material_ids: HashMap<[u32; 4], usize>,
+    meshes: Vec<String>,
+    nodes: Vec<String>,
+}
+
+impl Builder {
+    fn add_node(&mut self, node: &GltfNode, options: &GltfOptions) -> Result<usize, OcctError> {
+        let idx = self.nodes.len();
+
+        // Reserve the slot so parents precede their children
+        self.nodes.push(String::new());
+
+        let mut json = format!("{{\"name\":{}", json_str(&node.name));
+
+        if node.transform != DAffine3::IDENTITY {
+            json.push_str(&format!(",\"matrix\":{}", json_matrix(&node.transform)?));
+        }
+
+        if let Some(part) = node.mesh.as_ref() {
+            let key = part.mesh as *const ffi::MeshBlob as usize;
+            let mesh = part.mesh.view();
+
+            if let Some(mesh) = self.add_mesh(key, &mesh, part.color, &part.face_colors, options)? {
+                json.push_str(&format!(",\"mesh\":{}", mesh));
+            }
+        }
+
+        let children = node
+            .children
+            .iter()
+            .map(|child| self.add_node(child, options).map(|idx| idx.to_string()))
+            .collect::<Result<Vec<String>, OcctError>>()?;
+
+        if !children.is_empty() {
+            json.push_str(&format!(",\"children\":[{}]", children.join(",")));
+        }
+
+        json.push('}');
+        self.nodes[idx] = json;
+
+        Ok(idx)
+    }
+
+    /// Adds a glTF mesh for a part, `key` identifies the source blob so its
+    /// buffers are only written for the first part using it.
+    fn add_mesh(
+        &mut self,
+        key: usize,
+        mesh: &MeshView,
+        color: Color,
+        face_colors: &HashMap<usize, Color>,
+        options: &GltfOptions,
+    ) -> Result<Option<usize>, OcctError> {
+        let triangles = mesh.triangles;
+
+        // Edge-only meshes have nothing glTF triangles can show
+        if triangles.is_empty() {
+            return Ok(None);
+        }
+
+        let geometry = match self.geometries.get(&key) {
+            Some(&geometry) => geometry,
+            None => {
+                let geometry = self.add_geometry(mesh)?;
+
+                self.geometries.insert(key, geometry);
+                geometry
+            }
+        };
+        let faces = mesh.faces;
+        let ranges: Vec<(usize, usize, Color)> =
+            if options.primitives == GltfPrimitives::PerPart || faces.is_empty() {
+                vec![(0, triangles.len(), color)]
+            } else {
+                faces
+                    .iter()
+                    .map(|f| {
+                        let face_color = face_colors.get(&f.face_index()).copied();
+
+                        (
+                            f.first_tri as usize,
+                            f.tri_count as usize,
+                            face_color.unwrap_or(color),
+                        )
+                    })
+                    .collect()
+            };
+
+        let primitives = ranges
+            .into_iter()
+            .map(|(first_tri, tri_count, color)| {
+                let index = self.add_accessor(format!(
+                    "{{\"bufferView\":{},\"byteOffset\":{},\"componentType\":{},\"count\":{},\"type\":\"SCALAR\"}}",
+                    geometry.indices,
+                    first_tri * 12,
+                    UNSIGNED_INT,
+                    tri_count * 3
+                ));
+
+                Ok(format!(
+                    "{{\"attributes\":{{\"POSITION\":{},\"NORMAL\":{}}},\"indices\":{},\"material\":{}}}",
+                    geometry.position,
+                    geometry.normal,
+                    index,
+                    self.material(color)?
+                ))
+            })
+            .collect::<Result<Vec<String>, OcctError>>()?;
+
+        self.meshes
+            .push(format!("{{\"primitives\":[{}]}}", primitives.join(",")));
+
+        Ok(Some(self.meshes.len() - 1))
+    }
+
+    fn add_geometry(&mut self, mesh: &MeshView) -> Result<Geometry, OcctError> {
+        let vertices = mesh.vertices;
+        let mut min = Vec3::splat(f32::MAX);
+        let mut max = Vec3::splat(f32::MIN);
+
+        for &v in vertices {
+            min = min.min(Vec3::from(v));
+            max = max.max(Vec3::from(v));
+        }
+
+        let positions = self.add_view(
+            vertices.iter().flatten().copied().map(f32::to_bits),
+            ARRAY_BUFFER,
+        );
+        let position = self.add_accessor(format!(
+            "{{\"bufferView\":{},\"componentType\":{},\"count\":{},\"type\":\"VEC3\",\"min\":{},\"max\":{}}}",
+            positions,
+            FLOAT,
+            vertices.len(),
+            json_f32s(&min.to_array())?,
+            json_f32s(&max.to_array())?
+        ));
+
+        // glTF requires unit normals
+        let normals = mesh
+            .normals
+            .iter()
+            .flat_map(|&n| Vec3::from(n).try_normalize().unwrap_or(Vec3::Z).to_array())
+            .map(f32::to_bits);
+        let normals = self.add_view(normals, ARRAY_BUFFER);
+        let normal = self.add_accessor(format!(
+            "{{\"bufferView\":{},\"componentType\":{},\"count\":{},\"type\":\"VEC3\"}}",
+            normals,
+            FLOAT,
+            vertices.len()
+        ));
+
+        let indices = self.add_view(
+            mesh.triangles.iter().flatten().copied(),
+            ELEMENT_ARRAY_BUFFER,
+        );
+
+        Ok(Geometry {
+            position,
+            normal,
+            indices,
+        })
+    }
+
+    /// Appends 32-bit words to the binary buffer, keeping every view 4-byte aligned.
+    fn add_view<I: Iterator<Item = u32>>(&mut self, words: I, target: u32) -> usize {
+        let offset = self.bin.len();
+
+        for word in words {
+            self.bin.extend_from_slice(&word.to_le_bytes());
+        }
+
+        self.views.push(format!(
+            "{{\"buffer\":0,\"byteOffset\":{},\"byteLength\":{},\"target\":{}}}",
+            offset,
+            self.bin.len() - offset,
+            target
+        ));
+
+        self.views.len() - 1
+    }
+
+    fn add_accessor(&mut self, json: String) -> usize {
+        self.accessors.push(json);
+        self.accessors.len() - 1
+    }
+
+    fn material(&mut self, color: Color) -> Result<usize, OcctError> {
+        let key = color.map(f32::to_bits);
+
+        if let Some(&id) = self.material_ids.get(&key) {
+            return Ok(id);
+        }
+
+        let blend = if color[3] < 1.0 {
+            ",\"alphaMode\":\"BLEND\""
+        } else {
+            ""
+        };
+
+        self.materials.push(format!(
+            "{{\"pbrMetallicRoughness\":{{\"baseColorFactor\":{},\"metallicFactor\":0,\"roughnessFactor\":0.5}}{}}}",
+            json_f32s(&color)?,
+            blend
+        ));
+
+        let id = self.materials.len() - 1;
+
+        self.material_ids.insert(key, id);
+
+        Ok(id)
+    }
+
+    fn json(&self, roots: &[usize], uri: Option<&str>) -> String {
+        let roots: Vec<String> = roots.iter().map(|r| r.to_string()).collect();
+        let mut json = format!(
+            "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"occt\"}},\"scene\":0,\"scenes\":[{{\"nodes\":[{}]}}]",
+            roots.join(",")
+        );
+
+        if !self.nodes.is_empty() {
+            json.push_str(&format!(",\"nodes\":[{}]", self.nodes.join(",")));
+        }
+
+        if !self.bin.is_empty() {
+            let uri = uri
+                .map(|uri| format!("\"uri\":{},", json_str(uri)))
+                .unwrap_or_default();
+
+            json.push_str(&format!(
+                ",\"buffers\":[{{{}\"byteLength\":{}}}]",
+                uri,
+                self.bin.len()
+            ));
+        }
+
+        for (name, items) in [
+            ("bufferViews", &self.views),
+            ("accessors", &self.accessors),
+            ("materials", &self.materials),
+            ("meshes", &self.meshes),
+        ] {
+            if !items.is_empty() {
+                json.push_str(&format!(",\"{}\":[{}]", name, items.join(",")));
+            }
+        }
+
+        json.push('}');
+        json
+    }
+}
+
+/// Column-major node matrix, rejecting transforms glTF can't decompose.
+fn json_matrix(m: &DAffine3) -> Result<String, OcctError> {
+    let cols = [
+        m.matrix3.x_axis.extend(0.0),
+        m.matrix3.y_axis.extend(0.0),
+        m.matrix3.z_axis.extend(0.0),
+        m.translation.extend(1.0),
+    ];
+    let values: Vec<f64> = cols.iter().flat_map(|c| c.to_array()).collect();
+    let json = json_f64s(&values)?;
+
+    if !is_trs(&m.matrix3) {
+        return Err(OcctError::new(
+            ErrorKind::InvalidInput,
+            "glTF node transforms must not shear or collapse an axis",
+        ));
+    }
+
+    Ok(json)
+}
+
+/// glTF node matrices must decompose into translation, rotation and scale,
+/// so the axes have to stay orthogonal and non-zero.
+fn is_trs(m: &DMat3) -> bool {
+    let axes = [m.x_axis, m.y_axis, m.z_axis];
+    let orthogonal = [(0, 1), (0, 2), (1, 2)]
+        .iter()
+        .all(|&(i, j)| axes[i].dot(axes[j]).abs() <= 1e-6 * axes[i].length() * axes[j].length());
+
+    orthogonal && m.determinant() != 0.0
+}
+
+fn pad(data: &mut Vec<u8>, fill: u8) {
+    while !data.len().is_multiple_of(4) {
+        data.push(fill);
+    }
+}
+
+fn json_str(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+
+    json.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+// JSON has no NaN or infinity, writing them would corrupt the whole file
+fn check_finite(finite: bool) -> Result<(), OcctError> {
+    if finite {
+        Ok(())
+    } else {
+        Err(OcctError::new(
+            ErrorKind::InvalidInput,
+            "glTF colors, transforms and bounds must be finite",
+        ))
+    }
+}
+
+fn json_f32s(values: &[f32]) -> Result<String, OcctError> {
+    check_finite(values.iter().all(|v| v.is_finite()))?;
+
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+
+    Ok(format!("[{}]", values.join(",")))
+}
+
+fn json_f64s(values: &[f64]) -> Result<String, OcctError> {
+    check_finite(values.iter().all(|v| v.is_finite()))?;
+
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+
+    Ok(format!("[{}]", values.join(",")))
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut text = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                text.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                text.push('=');
+            }
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mesh::fixture;
+
+    #[test]
+    fn base64_padding() {
+        // RFC 4648 test vectors
+        let cases = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+
+        for (data, text) in cases {
+            assert_eq!(base64(data.as_bytes()), text);
+        }
+
+        assert_eq!(base64(&[0xff, 0xfe, 0xfd]), "//79");
+    }
+
+    #[test]
+    fn json_str_escaping() {
+        assert_eq!(json_str("part"), "\"part\"");
+        assert_eq!(json_str("a \"b\" \\ c"), "\"a \\\"b\\\" \\\\ c\"");
+        assert_eq!(json_str("line\nbreak\t"), "\"line\\u000abreak\\u0009\"");
+        assert_eq!(json_str("flasche ü"), "\"flasche ü\"");
+    }
+
+    #[test]
+    fn non_finite_values_are_rejected() {
+        assert_eq!(json_f32s(&[0.5, 1.0]).unwrap(), "[0.5,1]");
+
+        for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let err = json_f32s(&[0.0, value]).unwrap_err();
+
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+
+        assert!(json_f64s(&[f64::NAN]).is_err());
+    }
+
+    fn u32_at(data: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]])
+    }
+
+    #[test]
+    fn glb_chunks_are_aligned() {
+        let mut out = Vec::new();
+
+        write_glb(&mut out, b"{\"a\":1}".to_vec(), vec![1, 2, 3, 4, 5]).unwrap();
+
+        let json_len = u32_at(&out, 12) as usize;
+        let bin_at = 20 + json_len;
+        let bin_len = u32_at(&out, bin_at) as usize;
+
+        assert_eq!(u32_at(&out, 0), GLB_MAGIC);
+        assert_eq!(u32_at(&out, 4), 2);
+        assert_eq!(u32_at(&out, 8) as usize, out.len());
+        assert_eq!(u32_at(&out, 16), GLB_JSON_CHUNK);
+        assert_eq!(json_len, 8);
+        assert_eq!(&out[20..28], b"{\"a\":1} ");
+        assert_eq!(u32_at(&out, bin_at + 4), GLB_BIN_CHUNK);
+        assert_eq!(bin_len, 8);
+        assert_eq!(&out[bin_at + 8..], &[1, 2, 3, 4, 5, 0, 0, 0]);
+    }
+
+    #[test]
+    fn glb_without_buffer_has_no_bin_chunk() {
+        let mut out = Vec::new();
+
+        write_glb(&mut out, b"{}".to_vec(), Vec::new()).unwrap();
+
+        assert_eq!(u32_at(&out, 12), 4);
+        assert_eq!(&out[20..], b"{}  ");
+        assert_eq!(u32_at(&out, 8) as usize, out.len());
+    }
+
+    #[test]
+    fn per_face_primitives_share_aligned_views() {
+        // Normals are stored scaled, the writer normalizes them
+        let mesh = MeshView {
+            normals: &[[0.0, 0.0, 2.0]; 4],
+            ..fixture::square(&fixture::FACES)
+        };
+        let red = [1.0, 0.0, 0.0, 1.0];
+        let mut builder = Builder::default();
+        let face_colors = HashMap::from([(7, red)]);
+
+        builder
+            .add_mesh(
+                0,
+                &mesh,
+                DEFAULT_COLOR,
+                &face_colors,
+                &GltfOptions::default(),
+            )
+            .unwrap();
+
+        // Positions, normals and indices
+        assert_eq!(builder.bin.len(), 4 * 12 + 4 * 12 + 2 * 12);
+        assert!(builder.bin.len().is_multiple_of(4));
+        assert_eq!(builder.meshes.len(), 1);
+        assert_eq!(builder.meshes[0].matches("\"attributes\"").count(), 2);
+        assert_eq!(builder.materials.len(), 2);
+        assert!(builder.accessors[3].contains("\"byteOffset\":12"));
+        // Normals are rewritten as unit vectors
+        assert_eq!(f32::from_bits(u32_at(&builder.bin, 48 + 8)), 1.0);
+    }
+
+    #[test]
+    fn shared_blob_is_written_once() {
+        let mesh = fixture::square(&[]);
+        let mut builder = Builder::default();
+        let face_colors = HashMap::new();
+        let options = GltfOptions::default();
+
+        builder
+            .add_mesh(1, &mesh, DEFAULT_COLOR, &face_colors, &options)
+            .unwrap();
+
+        let bin_len = builder.bin.len();
+
+        builder
+            .add_mesh(1, &mesh, [0.0, 0.0, 1.0, 1.0], &face_colors, &options)
+            .unwrap();
+
+        assert_eq!(builder.bin.len(), bin_len);
+        assert_eq!(builder.views.len(), 3);
+        assert_eq!(builder.meshes.len(), 2);
+        assert_eq!(builder.materials.len(), 2);
+
+        builder
+            .add_mesh(2, &mesh, DEFAULT_COLOR, &face_colors, &options)
+            .unwrap();
+
+        assert_eq!(builder.bin.len(), 2 * bin_len);
+    }
+
+    #[test]
+    fn transforms_must_be_trs() {
+        use glam::{DQuat, DVec3};
+
+        let trs = DAffine3::from_scale_rotation_translation(
+            DVec3::new(2.0, 0.5, 3.0),
+            DQuat::from_rotation_y(0.3),
+            DVec3::new(1.0, 2.0, 3.0),
+        );
+        let shear = DMat3::from_cols(DVec3::X, DVec3::new(0.5, 1.0, 0.0), DVec3::Z);
+        let flat = DMat3::from_diagonal(DVec3::new(1.0, 0.0, 1.0));
+
+        assert!(is_trs(&trs.matrix3));
+        assert!(is_trs(&DMat3::from_diagonal(DVec3::new(-1.0, 1.0, 1.0))));
+        assert!(!is_trs(&shear));
+        assert!(!is_trs(&flat));
+
+        let err = json_matrix(&DAffine3::from_mat3(shear)).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,87 @@
+#include "occt/src/exchange_p.h"
+#include "occt/src/iges.h"
+#include "occt/src/iges.rs.h"
+
+#include <IFSelect_ReturnStatus.hxx>
+#include <IGESControl_Controller.hxx>
+#include <IGESControl_Reader.hxx>
+#include <IGESControl_Writer.hxx>
+
+namespace occt {
+
+std::unique_ptr<ShapeList> read_iges(rust::Str path, const IgesReadOptions& options)
+{
+    check_input(options.unit_scale > 0.0, "Unit scale must be positive");
+    check_input(!options.sew || options.sewing_tolerance > 0.0, "Sewing tolerance must be positive");
+
+    const std::string file(path);
+    std::lock_guard<std::mutex> lock(xstep_mutex());
+    IGESControl_Reader reader;
+
+    if(reader.ReadFile(file.c_str()) != IFSelect_RetDone)
+    {
+        throw Error::io("Failed to read IGES file " + file);
+    }
+
+    reader.TransferRoots();
+
+    if(reader.NbShapes() == 0)
+    {
+        throw Error::translation("No shapes translated from IGES file " + file);
+    }
+
+    std::vector<TopoDS_Shape> shapes;
+
+    for(int i = 1; i <= reader.NbShapes(); ++i)
+    {
+        shapes.push_back(scale_shape(reader.Shape(i), 1.0 / options.unit_scale));
+    }
+
+    if(options.sew)
+    {
+        shapes = sew_solids(shapes, options.sewing_tolerance);
+    }
+
+    auto result = std::make_unique<ShapeList>();
+
+    for(const TopoDS_Shape& shape : shapes)
+    {
+        result->push(shape);
+    }
+
+    return result;
+}
+
+void write_iges(const ShapeList& shapes, rust::Str path, rust::Str unit, double unit_scale)
+{
+    check_input(!shapes.items().empty(), "Nothing to write");
+    check_input(unit_scale > 0.0, "Unit scale must be positive");
+
+    const std::string file(path);
+    const std::string unit_name(unit);
+    std::lock_guard<std::mutex> lock(xstep_mutex());
+
+    IGESControl_Controller::Init();
+
+    // B-rep mode keeps solids and shells instead of loose trimmed surfaces
+    IGESControl_Writer writer(unit_name.c_str(), 1);
+
+    for(const TopoDS_Shape& shape : shapes.items())
+    {
+        check_input(!shape.IsNull(), "Can't write null shape");
+
+        if(!writer.AddShape(scale_shape(shape, unit_scale)))
+        {
+            throw Error::translation("Failed to translate shape to IGES");
+        }
+    }
+
+    writer.ComputeModel();
+
+    if(!writer.Write(file.c_str()))
+    {
+        throw Error::io("Failed to write IGES file " + file);
+    }
+}
+
+} // namespace occt
@@ -0,0 +1,12 @@
+#pragma once
+
+#include "occt/src/occt.h"
+
+namespace occt {
+
+struct IgesReadOptions;
+
+std::unique_ptr<ShapeList> read_iges(rust::Str path, const IgesReadOptions& options);
+void write_iges(const ShapeList& shapes, rust::Str path, rust::Str unit, double unit_scale);
+
+} // namespace occt
@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use boolean::shape_list;
+use error::{path_str, OcctError};
+use exchange::LengthUnit;
+use shape::Shape;
+use topology::ShapeIter;
+
+#[cxx::bridge(namespace = "occt")]
+mod ffi {
+    struct IgesReadOptions {
+        unit_scale: f64,
+        sew: bool,
+        sewing_tolerance: f64,
+    }
+
+    unsafe extern "C++" {
+        include!("occt/src/iges.h");
+
+        type Shape = crate::occt::ffi::Shape;
+        type ShapeList = crate::occt::ffi::ShapeList;
+
+        fn read_iges(path: &str, options: &IgesReadOptions) -> Result<UniquePtr<ShapeList>>;
+        fn write_iges(shapes: &ShapeList, path: &str, unit: &str, unit_scale: f64) -> Result<()>;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IgesReadOptions {
+    /// Unit the geometry is converted to.
+    pub unit: LengthUnit,
+    /// Sew trimmed surfaces along shared boundaries, closed shells become solids.
+    pub sew: bool,
+    /// Maximum gap closed by sewing, in `unit`.
+    pub sewing_tolerance: f64,
+}
+
+impl Default for IgesReadOptions {
+    fn default() -> Self {
+        Self {
+            unit: LengthUnit::Millimeter,
+            sew: false,
+            sewing_tolerance: 1.0e-6,
+        }
+    }
+}
+
+impl IgesReadOptions {
+    /// Sewing enabled, suitable for surface models meant to be meshed as solids.
+    pub fn sewn(tolerance: f64) -> Self {
+        Self {
+            sew: true,
+            sewing_tolerance: tolerance,
+            ..Default::default()
+        }
+    }
+}
+
+/// Reads every root of an IGES file as is, geometry is returned in millimeters.
+pub fn read_iges<P: AsRef<Path>>(path: P) -> Result<Vec<Shape>, OcctError> {
+    read_iges_with_options(path, &IgesReadOptions::default())
+}
+
+pub fn read_iges_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &IgesReadOptions,
+) -> Result<Vec<Shape>, OcctError> {
+    let options = ffi::IgesReadOptions {
+        unit_scale: options.unit.millimeters(),
+        sew: options.sew,
+        sewing_tolerance: options.sewing_tolerance,
+    };
+    let list = ffi::read_iges(path_str(path.as_ref())?, &options)?;
+
+    Ok(ShapeIter::new(list).collect())
+}
+
+/// Writes `shapes` in B-rep mode, coordinates are taken as millimeters.
+pub fn write_iges<P: AsRef<Path>>(shapes: &[Shape], path: P) -> Result<(), OcctError> {
+    write_iges_with_unit(shapes, path, LengthUnit::Millimeter)
+}
+
+/// Writes `shapes` whose coordinates are in `unit`, the file declares the same unit.
+pub fn write_iges_with_unit<P: AsRef<Path>>(
+    shapes: &[Shape],
+    path: P,
+    unit: LengthUnit,
+) -> Result<(), OcctError> {
+    let list = shape_list(&shapes.iter().collect::<Vec<_>>());
+
+    Ok(ffi::write_iges(
+        &list,
+        path_str(path.as_ref())?,
+        unit.xstep_name(),
+        unit.millimeters(),
+    )?)
+}
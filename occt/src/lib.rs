@@ -1,9 +1,57 @@
 extern crate cxx;
+extern crate glam;
+extern crate threadpool;
 
+mod boolean;
+mod brep;
+mod classify;
+mod distance;
+mod error;
+#[cfg(any(feature = "step", feature = "iges"))]
+mod exchange;
+mod fillet;
+mod flask;
+mod geometry;
+mod gltf;
+#[cfg(feature = "iges")]
+mod iges;
+mod mesh;
+mod obj;
 mod occt;
+mod placement;
+mod ply;
+mod primitives;
+mod props;
+mod shape;
+mod sketch;
+mod stl;
+mod sweep;
+mod topology;
+#[cfg(feature = "step")]
+mod step;
 
-pub type MeshBlob = cxx::UniquePtr<occt::ffi::MeshBlob>;
-
-pub fn make_flask(width: f64, thickness: f64, height: f64) -> MeshBlob {
-    occt::ffi::make_flask(width, thickness, height)
-}
+pub use self::boolean::*;
+pub use self::classify::*;
+pub use self::distance::*;
+pub use self::error::*;
+#[cfg(any(feature = "step", feature = "iges"))]
+pub use self::exchange::*;
+pub use self::fillet::*;
+pub use self::flask::*;
+pub use self::geometry::*;
+pub use self::gltf::*;
+#[cfg(feature = "iges")]
+pub use self::iges::*;
+pub use self::mesh::*;
+pub use self::obj::*;
+pub use self::placement::*;
+pub use self::ply::*;
+pub use self::primitives::*;
+pub use self::props::*;
+pub use self::shape::*;
+pub use self::sketch::*;
+pub use self::stl::*;
+pub use self::sweep::*;
+pub use self::topology::*;
+#[cfg(feature = "step")]
+pub use self::step::*;
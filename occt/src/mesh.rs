@@ -0,0 +1,311 @@
+use std::ops::Range;
+use std::slice;
+
+use error::OcctError;
+use occt::ffi;
+use shape::Shape;
+
+pub use occt::ffi::{MeshEdge, MeshFace};
+
+pub type MeshBlob = cxx::UniquePtr<ffi::MeshBlob>;
+
+/// Linear RGBA, components in 0..=1, shared by the exporters that write colors.
+pub type Color = [f32; 4];
+
+/// Owned copy of the `MeshBlob` buffers.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshData {
+    pub vertices: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub triangles: Vec<[u32; 3]>,
+    pub faces: Vec<MeshFace>,
+    pub edge_vertices: Vec<[f32; 3]>,
+    pub edges: Vec<MeshEdge>,
+}
+
+/// Borrowed mesh buffers the exporters work on.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MeshView<'a> {
+    pub vertices: &'a [[f32; 3]],
+    pub normals: &'a [[f32; 3]],
+    pub triangles: &'a [[u32; 3]],
+    pub faces: &'a [MeshFace],
+}
+
+impl MeshFace {
+    /// Index of the source face in the shape's face map.
+    pub fn face_index(&self) -> usize {
+        self.face as usize
+    }
+
+    pub fn triangle_range(&self) -> Range<usize> {
+        self.first_tri as usize..self.first_tri as usize + self.tri_count as usize
+    }
+
+    pub fn vertex_range(&self) -> Range<usize> {
+        self.first_vert as usize..self.first_vert as usize + self.vert_count as usize
+    }
+}
+
+impl MeshEdge {
+    /// Index of the source edge in the shape's edge map.
+    pub fn edge_index(&self) -> usize {
+        self.edge as usize
+    }
+
+    /// Range of the polyline points in `edge_vertices`.
+    pub fn vertex_range(&self) -> Range<usize> {
+        self.first_vert as usize..self.first_vert as usize + self.vert_count as usize
+    }
+}
+
+unsafe fn items<'a, T>(ptr: *const T, count: u32) -> &'a [T] {
+    if count == 0 || ptr.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, count as usize)
+    }
+}
+
+unsafe fn triples<'a, T>(ptr: *const T, count: u32) -> &'a [[T; 3]] {
+    items(ptr as *const [T; 3], count)
+}
+
+impl ffi::MeshBlob {
+    pub fn vertices(&self) -> &[[f32; 3]] {
+        let raw = self.verts();
+
+        // Buffers are owned by the blob and never change after construction
+        unsafe { triples(raw.ptr, raw.count) }
+    }
+
+    pub fn normals(&self) -> &[[f32; 3]] {
+        let raw = self.norms();
+
+        unsafe { triples(raw.ptr, raw.count) }
+    }
+
+    pub fn triangles(&self) -> &[[u32; 3]] {
+        let raw = self.tris();
+
+        unsafe { triples(raw.ptr, raw.count) }
+    }
+
+    /// Face table ordered by triangle range, faces without triangulation are skipped.
+    pub fn faces(&self) -> &[MeshFace] {
+        let raw = self.raw_faces();
+
+        unsafe { items(raw.ptr, raw.count) }
+    }
+
+    /// Polyline points of all edges, see `edges` for per-edge ranges.
+    pub fn edge_vertices(&self) -> &[[f32; 3]] {
+        let raw = self.edge_verts();
+
+        unsafe { triples(raw.ptr, raw.count) }
+    }
+
+    /// Discretized B-rep edges, degenerated edges are skipped.
+    pub fn edges(&self) -> &[MeshEdge] {
+        let raw = self.raw_edges();
+
+        unsafe { items(raw.ptr, raw.count) }
+    }
+
+    pub fn edge_polyline(&self, edge: &MeshEdge) -> &[[f32; 3]] {
+        &self.edge_vertices()[edge.vertex_range()]
+    }
+
+    /// Maps a picked triangle back to the face it was generated from.
+    pub fn face_of_triangle(&self, triangle: usize) -> Option<&MeshFace> {
+        let faces = self.faces();
+        let idx = faces.partition_point(|f| (f.first_tri as usize) <= triangle);
+
+        idx.checked_sub(1)
+            .map(|i| &faces[i])
+            .filter(|f| f.triangle_range().contains(&triangle))
+    }
+
+    /// Builds a B-rep from the triangles, one planar face each, sewn along
+    /// edges closer than `tolerance`. Closed shells become solids, several
+    /// pieces are returned as a compound.
+    pub fn to_shape(&self, tolerance: f64) -> Result<Shape, OcctError> {
+        Ok(Shape::from_ffi(ffi::sew_mesh(self, tolerance)?))
+    }
+
+    pub(crate) fn view(&self) -> MeshView<'_> {
+        MeshView {
+            vertices: self.vertices(),
+            normals: self.normals(),
+            triangles: self.triangles(),
+            faces: self.faces(),
+        }
+    }
+
+    pub fn to_data(&self) -> MeshData {
+        MeshData {
+            vertices: self.vertices().to_vec(),
+            normals: self.normals().to_vec(),
+            triangles: self.triangles().to_vec(),
+            faces: self.faces().to_vec(),
+            edge_vertices: self.edge_vertices().to_vec(),
+            edges: self.edges().to_vec(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LinearDeflection {
+    /// Maximum chordal distance in model units.
+    Absolute(f64),
+    /// Fraction of each edge/face size.
+    Relative(f64),
+}
+
+/// Tessellation settings for `mesh`, see `IMeshTools_Parameters`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeshParams {
+    pub linear_deflection: LinearDeflection,
+    /// Maximum angle between adjacent segments, radians.
+    pub angular_deflection: f64,
+    /// Deflections for face interiors, `None` to use the edge ones.
+    pub interior_linear_deflection: Option<f64>,
+    pub interior_angular_deflection: Option<f64>,
+    /// Minimum element size, `None` to derive it from the deflection.
+    pub min_element_size: Option<f64>,
+    pub in_parallel: bool,
+    /// Refine faces until the surface, not only edges, satisfies the deflection.
+    pub control_surface_deflection: bool,
+}
+
+impl Default for MeshParams {
+    fn default() -> Self {
+        Self {
+            linear_deflection: LinearDeflection::Absolute(0.01),
+            angular_deflection: 12.0f64.to_radians(),
+            interior_linear_deflection: None,
+            interior_angular_deflection: None,
+            min_element_size: None,
+            in_parallel: false,
+            control_surface_deflection: true,
+        }
+    }
+}
+
+impl MeshParams {
+    pub fn coarse() -> Self {
+        Self {
+            linear_deflection: LinearDeflection::Relative(0.1),
+            angular_deflection: 30.0f64.to_radians(),
+            control_surface_deflection: false,
+            ..Default::default()
+        }
+    }
+
+    pub fn fine() -> Self {
+        Self {
+            linear_deflection: LinearDeflection::Absolute(0.001),
+            angular_deflection: 5.0f64.to_radians(),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn to_ffi(self) -> ffi::MeshParams {
+        let (deflection, relative) = match self.linear_deflection {
+            LinearDeflection::Absolute(value) => (value, false),
+            LinearDeflection::Relative(value) => (value, true),
+        };
+
+        ffi::MeshParams {
+            deflection,
+            angle: self.angular_deflection,
+            deflection_interior: self.interior_linear_deflection.unwrap_or(-1.0),
+            angle_interior: self.interior_angular_deflection.unwrap_or(-1.0),
+            min_size: self.min_element_size.unwrap_or(-1.0),
+            relative,
+            in_parallel: self.in_parallel,
+            control_surface_deflection: self.control_surface_deflection,
+        }
+    }
+}
+
+/// Tessellates `shape`, the shape itself is left untouched so it can be meshed
+/// again with other settings.
+pub fn mesh(shape: &Shape, params: &MeshParams) -> Result<MeshBlob, OcctError> {
+    Ok(ffi::mesh_shape(shape.as_ffi(), &params.to_ffi())?)
+}
+
+/// Mesh shared by the exporter tests.
+#[cfg(test)]
+pub(crate) mod fixture {
+    use super::{MeshFace, MeshView};
+
+    /// Unit square in the XY plane facing +Z.
+    pub const VERTICES: [[f32; 3]; 4] = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ];
+    pub const NORMALS: [[f32; 3]; 4] = [[0.0, 0.0, 1.0]; 4];
+    pub const TRIANGLES: [[u32; 3]; 2] = [[0, 1, 2], [0, 2, 3]];
+    /// One triangle per source face, the faces share the diagonal vertices.
+    pub const FACES: [MeshFace; 2] = [
+        MeshFace {
+            face: 3,
+            first_tri: 0,
+            tri_count: 1,
+            first_vert: 0,
+            vert_count: 3,
+        },
+        MeshFace {
+            face: 7,
+            first_tri: 1,
+            tri_count: 1,
+            first_vert: 0,
+            vert_count: 4,
+        },
+    ];
+
+    pub fn square(faces: &'static [MeshFace]) -> MeshView<'static> {
+        MeshView {
+            vertices: &VERTICES,
+            normals: &NORMALS,
+            triangles: &TRIANGLES,
+            faces,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn face_ranges_widen_before_adding() {
+        let face = MeshFace {
+            face: 0,
+            first_tri: u32::MAX - 1,
+            tri_count: 3,
+            first_vert: u32::MAX,
+            vert_count: u32::MAX,
+        };
+
+        assert_eq!(face.triangle_range().end, u32::MAX as usize + 2);
+        assert_eq!(face.vertex_range().end, 2 * u32::MAX as usize);
+    }
+
+    #[test]
+    fn edge_range_widens_before_adding() {
+        let edge = MeshEdge {
+            edge: 0,
+            first_vert: u32::MAX,
+            vert_count: 2,
+        };
+
+        assert_eq!(
+            edge.vertex_range(),
+            u32::MAX as usize..u32::MAX as usize + 2
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use error::OcctError;
+use mesh::MeshView;
+use occt::ffi;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObjOptions {
+    pub normals: bool,
+    /// Emit a `g face_<index>` group per B-rep face.
+    pub face_groups: bool,
+}
+
+impl Default for ObjOptions {
+    fn default() -> Self {
+        Self {
+            normals: true,
+            face_groups: true,
+        }
+    }
+}
+
+impl ffi::MeshBlob {
+    pub fn write_obj<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: &ObjOptions,
+    ) -> Result<(), OcctError> {
+        let mut out = BufWriter::new(File::create(path)?);
+
+        self.write_obj_to(&mut out, options)?;
+
+        Ok(out.flush()?)
+    }
+
+    pub fn write_obj_to<W: Write>(&self, out: W, options: &ObjOptions) -> Result<(), OcctError> {
+        write_obj_mesh(&self.view(), out, options)
+    }
+}
+
+fn write_obj_mesh<W: Write>(
+    mesh: &MeshView,
+    mut out: W,
+    options: &ObjOptions,
+) -> Result<(), OcctError> {
+    writeln!(out, "# occt")?;
+
+    for v in mesh.vertices {
+        writeln!(out, "v {} {} {}", v[0], v[1], v[2])?;
+    }
+
+    if options.normals {
+        for n in mesh.normals {
+            writeln!(out, "vn {} {} {}", n[0], n[1], n[2])?;
+        }
+    }
+
+    if options.face_groups && !mesh.faces.is_empty() {
+        for face in mesh.faces {
+            writeln!(out, "g face_{}", face.face_index())?;
+            write_obj_faces(&mut out, &mesh.triangles[face.triangle_range()], options)?;
+        }
+    } else {
+        write_obj_faces(&mut out, mesh.triangles, options)?;
+    }
+
+    Ok(())
+}
+
+fn write_obj_faces<W: Write>(
+    out: &mut W,
+    triangles: &[[u32; 3]],
+    options: &ObjOptions,
+) -> Result<(), OcctError> {
+    for tri in triangles {
+        // OBJ indices are 1-based
+        let [a, b, c] = tri.map(|i| i + 1);
+
+        if options.normals {
+            writeln!(out, "f {a}//{a} {b}//{b} {c}//{c}")?;
+        } else {
+            writeln!(out, "f {a} {b} {c}")?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mesh::{fixture, MeshFace};
+
+    fn write(faces: &'static [MeshFace], options: &ObjOptions) -> Vec<String> {
+        let mut out = Vec::new();
+
+        write_obj_mesh(&fixture::square(faces), &mut out, options).unwrap();
+
+        String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn face_groups() {
+        let lines = write(&fixture::FACES, &ObjOptions::default());
+
+        assert_eq!(lines.len(), 1 + 4 + 4 + 2 * 2);
+        assert_eq!(lines[1], "v 0 0 0");
+        assert_eq!(lines[5], "vn 0 0 1");
+        assert_eq!(lines[9], "g face_3");
+        assert_eq!(lines[10], "f 1//1 2//2 3//3");
+        assert_eq!(lines[11], "g face_7");
+        assert_eq!(lines[12], "f 1//1 3//3 4//4");
+    }
+
+    #[test]
+    fn plain_faces() {
+        let options = ObjOptions {
+            normals: false,
+            face_groups: true,
+        };
+        let lines = write(&[], &options);
+
+        assert_eq!(lines.len(), 1 + 4 + 2);
+        assert!(lines
+            .iter()
+            .all(|l| !l.starts_with("vn") && !l.starts_with('g')));
+        assert_eq!(lines[5], "f 1 2 3");
+        assert_eq!(lines[6], "f 1 3 4");
+    }
+}
@@ -1,31 +1,27 @@
-#include "occt/src/occt.h"
+#include "occt/src/occt_p.h"
 
 #include <cstdint>
+#include <utility>
 #include <vector>
 
-#include "occt/src/occt.rs.h"
-
-#include <BRepAlgoAPI_Fuse.hxx>
-#include <BRepBuilderAPI_MakeEdge.hxx>
-#include <BRepBuilderAPI_MakeFace.hxx>
-#include <BRepBuilderAPI_MakeWire.hxx>
-#include <BRepBuilderAPI_Transform.hxx>
-#include <BRepFilletAPI_MakeFillet.hxx>
-#include <BRepMesh_DiscretFactory.hxx>
-#include <BRepPrimAPI_MakeCylinder.hxx>
-#include <BRepPrimAPI_MakePrism.hxx>
+#include <BRep_Tool.hxx>
+#include <BRepBuilderAPI_Copy.hxx>
+#include <BRepMesh_IncrementalMesh.hxx>
 #include <BRepTools.hxx>
-#include <GC_MakeArcOfCircle.hxx>
-#include <GC_MakeSegment.hxx>
-#include <Geom_TrimmedCurve.hxx>
-#include <gp_Ax1.hxx>
 #include <gp_Pnt.hxx>
-#include <TopExp_Explorer.hxx>
+#include <gp_Trsf.hxx>
+#include <IMeshTools_Parameters.hxx>
+#include <Poly_Polygon3D.hxx>
+#include <Poly_PolygonOnTriangulation.hxx>
+#include <Poly_Triangulation.hxx>
+#include <TColStd_Array1OfInteger.hxx>
+#include <TopExp.hxx>
 #include <TopLoc_Location.hxx>
 #include <TopoDS.hxx>
 #include <TopoDS_Edge.hxx>
 #include <TopoDS_Face.hxx>
-#include <TopoDS_Wire.hxx>
+#include <TopTools_IndexedDataMapOfShapeListOfShape.hxx>
+#include <TopTools_IndexedMapOfShape.hxx>
 
 namespace occt {
 
@@ -48,6 +44,9 @@ struct MeshBlobInit
     std::vector<Vertex> verts;
     std::vector<Vertex> norms;
     std::vector<Triangle> tris;
+    std::vector<MeshFace> faces;
+    std::vector<Vertex> edge_verts;
+    std::vector<MeshEdge> edges;
 };
 
 struct MeshBlob::Impl
@@ -61,6 +60,9 @@ struct MeshBlob::Impl
     std::vector<Vertex> verts;
     std::vector<Vertex> norms;
     std::vector<Triangle> tris;
+    std::vector<MeshFace> faces;
+    std::vector<Vertex> edge_verts;
+    std::vector<MeshEdge> edges;
     MeshBbox bbox;
 };
 
@@ -76,6 +78,8 @@ MeshBlob::MeshBlob() noexcept
     m_d->norms.push_back({0.0f, 0.0f, 1.0f});
 
     m_d->tris.push_back({0, 1, 2});
+
+    m_d->faces.push_back({0, 0, 1, 0, 3});
 }
 
 MeshBlob::MeshBlob(MeshBlobInit init) noexcept
@@ -84,13 +88,18 @@ MeshBlob::MeshBlob(MeshBlobInit init) noexcept
     m_d->verts = std::move(init.verts);
     m_d->norms = std::move(init.norms);
     m_d->tris = std::move(init.tris);
+    m_d->faces = std::move(init.faces);
+    m_d->edge_verts = std::move(init.edge_verts);
+    m_d->edges = std::move(init.edges);
+
+    const std::vector<Vertex>& first = m_d->verts.empty() ? m_d->edge_verts : m_d->verts;
 
-    if(!m_d->verts.empty())
+    if(!first.empty())
     {
-        Tuple3f min = {m_d->verts[0].x, m_d->verts[0].y, m_d->verts[0].z};
+        Tuple3f min = {first[0].x, first[0].y, first[0].z};
         Tuple3f max = min;
 
-        for(const Vertex& v : m_d->verts)
+        auto extend = [&min, &max](const Vertex& v)
         {
             if(min.x > v.x)
             {
@@ -121,6 +130,16 @@ MeshBlob::MeshBlob(MeshBlobInit init) noexcept
             {
                 max.z = v.z;
             }
+        };
+
+        for(const Vertex& v : m_d->verts)
+        {
+            extend(v);
+        }
+
+        for(const Vertex& v : m_d->edge_verts)
+        {
+            extend(v);
         }
 
         m_d->bbox.min = min;
@@ -168,82 +187,80 @@ MeshTris MeshBlob::tris() const noexcept
     return raw;
 }
 
-std::unique_ptr<MeshBlob> make_flask(double width, double thickness, double height) noexcept
+MeshFaces MeshBlob::faces() const noexcept
 {
-    TopoDS_Shape body;
-
-    // Extrude
-    {
-        gp_Pnt pts[5] = {
-            {-width * 0.5, 0.0,               0.0},
-            {-width * 0.5, 0.0, -thickness * 0.25},
-            {         0.0, 0.0,  -thickness * 0.5},
-            { width * 0.5, 0.0, -thickness * 0.25},
-            { width * 0.5, 0.0,               0.0}
-        };
+    MeshFaces raw;
 
-        Handle(Geom_TrimmedCurve) arc = GC_MakeArcOfCircle(pts[1], pts[2], pts[3]);
-        Handle(Geom_TrimmedCurve) seg1 = GC_MakeSegment(pts[0], pts[1]);
-        Handle(Geom_TrimmedCurve) seg2 = GC_MakeSegment(pts[3], pts[4]);
+    raw.count = static_cast<std::uint32_t>(m_d->faces.size());
+    raw.ptr = m_d->faces.data();
 
-        TopoDS_Wire wire1 = BRepBuilderAPI_MakeWire(BRepBuilderAPI_MakeEdge(seg1),
-            BRepBuilderAPI_MakeEdge(arc),
-            BRepBuilderAPI_MakeEdge(seg2));
-
-        gp_Trsf xf;
+    return raw;
+}
 
-        xf.SetMirror(gp::OX());
+MeshVerts MeshBlob::edge_verts() const noexcept
+{
+    MeshVerts raw;
 
-        TopoDS_Wire wire2 = TopoDS::Wire(BRepBuilderAPI_Transform(wire1, xf).Shape());
+    raw.count = static_cast<std::uint32_t>(m_d->edge_verts.size());
+    raw.ptr = reinterpret_cast<const float*>(m_d->edge_verts.data());
 
-        BRepBuilderAPI_MakeWire mk_wire;
+    return raw;
+}
 
-        mk_wire.Add(wire1);
-        mk_wire.Add(wire2);
+MeshEdges MeshBlob::edges() const noexcept
+{
+    MeshEdges raw;
 
-        body = BRepPrimAPI_MakePrism(BRepBuilderAPI_MakeFace(mk_wire.Wire()), gp_Vec(0.0, height, 0.0));
-    }
+    raw.count = static_cast<std::uint32_t>(m_d->edges.size());
+    raw.ptr = m_d->edges.data();
 
-    // Fillet cask
-    {
-        BRepFilletAPI_MakeFillet mk_fillet(body);
+    return raw;
+}
 
-        for(TopExp_Explorer edge_i(body, TopAbs_EDGE); edge_i.More(); edge_i.Next())
-        {
-            mk_fillet.Add(thickness / 12.0, TopoDS::Edge(edge_i.Current()));
-        }
+std::unique_ptr<MeshBlob> mesh_body(TopoDS_Shape body, const MeshParams& params)
+{
+    check_input(params.deflection > 0.0, "Mesh deflection must be positive");
+    check_input(params.angle > 0.0, "Mesh angular deflection must be positive");
 
-        body = mk_fillet.Shape();
-    }
+    BRepTools::Clean(body);
 
-    // Fuse with neck
-    {
-        body = BRepAlgoAPI_Fuse(body, BRepPrimAPI_MakeCylinder(gp_Ax2(gp_Pnt(0.0, height, 0.0), gp::DY()), thickness / 4., height / 10.));
-    }
+    // Non-positive interior values and min size are replaced by the mesher defaults
+    IMeshTools_Parameters mesh_params;
 
-    // Mesh it
-    BRepTools::Clean(body);
+    mesh_params.Deflection = params.deflection;
+    mesh_params.Angle = params.angle;
+    mesh_params.DeflectionInterior = params.deflection_interior;
+    mesh_params.AngleInterior = params.angle_interior;
+    mesh_params.MinSize = params.min_size;
+    mesh_params.Relative = params.relative;
+    mesh_params.InParallel = params.in_parallel;
+    mesh_params.ControlSurfaceDeflection = params.control_surface_deflection;
 
-    Handle(BRepMesh_DiscretRoot) discret_algo = BRepMesh_DiscretFactory::Get().Discret(body, 0.01, 12.0 * M_PI / 180.0);
+    BRepMesh_IncrementalMesh mesher(body, mesh_params);
 
-    if(!discret_algo.IsNull())
+    if(!mesher.IsDone())
     {
-        discret_algo->Perform();
+        throw Error::mesh("Meshing failed");
     }
 
     std::vector<Vertex> verts;
     std::vector<Vertex> norms;
     std::vector<Triangle> tris;
+    std::vector<MeshFace> faces;
 
     verts.reserve(1024);
     norms.reserve(1024);
     tris.reserve(1024);
 
     TopLoc_Location loc;
+    // Face ids are indices in this map, stable for a given shape
+    TopTools_IndexedMapOfShape face_map;
 
-    for(TopExp_Explorer face_i(body, TopAbs_FACE); face_i.More(); face_i.Next())
+    TopExp::MapShapes(body, TopAbs_FACE, face_map);
+
+    for(int face_idx = 1; face_idx <= face_map.Extent(); ++face_idx)
     {
-        TopoDS_Face face = TopoDS::Face(face_i.Current());
+        TopoDS_Face face = TopoDS::Face(face_map(face_idx));
         Handle(Poly_Triangulation) face_tri = BRep_Tool::Triangulation(face, loc);
 
         if(face_tri.IsNull())
@@ -259,6 +276,7 @@ std::unique_ptr<MeshBlob> make_flask(double width, double thickness, double heig
         }
 
         const std::uint32_t vert_start = static_cast<std::uint32_t>(verts.size());
+        const std::uint32_t tri_start = static_cast<std::uint32_t>(tris.size());
 
         if(!face_tri->HasNormals())
         {
@@ -320,10 +338,110 @@ std::unique_ptr<MeshBlob> make_flask(double width, double thickness, double heig
 
             face_tri->Triangle(i).Get(idx[0], idx[1], idx[2]);
 
+            // Keep triangles counter-clockwise around the outward normal
+            if(face.Orientation() == TopAbs_REVERSED)
+            {
+                std::swap(idx[1], idx[2]);
+            }
+
             tris.push_back({vert_start + static_cast<std::uint32_t>(idx[0] - 1),
                 vert_start + static_cast<std::uint32_t>(idx[1] - 1),
                 vert_start + static_cast<std::uint32_t>(idx[2] - 1)});
         }
+
+        faces.push_back({static_cast<std::uint32_t>(face_idx - 1),
+            tri_start,
+            static_cast<std::uint32_t>(triangles_n),
+            vert_start,
+            static_cast<std::uint32_t>(nodes_n)});
+    }
+
+    std::vector<Vertex> edge_verts;
+    std::vector<MeshEdge> edges;
+
+    // Edge ids are indices in TopExp::MapShapes. The ancestor map appends
+    // free edges at the end, so it is only used to look up faces
+    TopTools_IndexedMapOfShape edge_map;
+    TopTools_IndexedDataMapOfShapeListOfShape edge_faces;
+
+    TopExp::MapShapes(body, TopAbs_EDGE, edge_map);
+    TopExp::MapShapesAndAncestors(body, TopAbs_EDGE, TopAbs_FACE, edge_faces);
+
+    for(int edge_idx = 1; edge_idx <= edge_map.Extent(); ++edge_idx)
+    {
+        const TopoDS_Edge edge = TopoDS::Edge(edge_map(edge_idx));
+
+        if(BRep_Tool::Degenerated(edge))
+        {
+            continue;
+        }
+
+        const std::uint32_t vert_start = static_cast<std::uint32_t>(edge_verts.size());
+        auto push_pnt = [&edge_verts](const gp_Pnt& pos)
+        {
+            edge_verts.push_back({static_cast<float>(pos.X()),
+                static_cast<float>(pos.Y()),
+                static_cast<float>(pos.Z())});
+        };
+
+        // Free edges carry their own polygon, edges bounding faces are
+        // discretized as polygons on the face triangulation
+        Handle(Poly_Polygon3D) poly = BRep_Tool::Polygon3D(edge, loc);
+
+        if(!poly.IsNull())
+        {
+            const gp_Trsf trsf = loc.Transformation();
+
+            for(int i = 1; i <= poly->NbNodes(); ++i)
+            {
+                push_pnt(poly->Nodes().Value(i).Transformed(trsf));
+            }
+        }
+        else
+        {
+            for(const TopoDS_Shape& face : edge_faces.FindFromKey(edge))
+            {
+                Handle(Poly_Triangulation) face_tri = BRep_Tool::Triangulation(TopoDS::Face(face), loc);
+
+                if(face_tri.IsNull())
+                {
+                    continue;
+                }
+
+                Handle(Poly_PolygonOnTriangulation) poly_on_tri = BRep_Tool::PolygonOnTriangulation(edge, face_tri, loc);
+
+                if(poly_on_tri.IsNull())
+                {
+                    continue;
+                }
+
+                const gp_Trsf trsf = loc.Transformation();
+                const TColStd_Array1OfInteger& nodes = poly_on_tri->Nodes();
+
+                for(int i = nodes.Lower(); i <= nodes.Upper(); ++i)
+                {
+                    push_pnt(face_tri->Node(nodes.Value(i)).Transformed(trsf));
+                }
+
+                break;
+            }
+        }
+
+        const std::uint32_t verts_n = static_cast<std::uint32_t>(edge_verts.size()) - vert_start;
+
+        if(verts_n > 1)
+        {
+            edges.push_back({static_cast<std::uint32_t>(edge_idx - 1), vert_start, verts_n});
+        }
+        else
+        {
+            edge_verts.resize(vert_start);
+        }
+    }
+
+    if(tris.empty() && edges.empty())
+    {
+        throw Error::mesh("Meshing produced neither triangles nor edges");
     }
 
     MeshBlobInit init;
@@ -331,8 +449,43 @@ std::unique_ptr<MeshBlob> make_flask(double width, double thickness, double heig
     init.verts = std::move(verts);
     init.norms = std::move(norms);
     init.tris = std::move(tris);
+    init.faces = std::move(faces);
+    init.edge_verts = std::move(edge_verts);
+    init.edges = std::move(edges);
 
     return std::make_unique<MeshBlob>(std::move(init));
 }
 
-} // namespace occt
\ No newline at end of file
+std::unique_ptr<MeshBlob> new_mesh_blob(rust::Slice<const float> verts, rust::Slice<const float> norms, rust::Slice<const std::uint32_t> tris)
+{
+    check_input(verts.size() % 3 == 0 && tris.size() % 3 == 0, "Mesh buffers must hold triples");
+    check_input(norms.size() == verts.size(), "Mesh needs a normal per vertex");
+
+    const std::size_t verts_n = verts.size() / 3;
+    MeshBlobInit init;
+
+    for(std::size_t i = 0; i < verts_n; ++i)
+    {
+        init.verts.push_back({verts[3 * i], verts[3 * i + 1], verts[3 * i + 2]});
+        init.norms.push_back({norms[3 * i], norms[3 * i + 1], norms[3 * i + 2]});
+    }
+
+    for(std::size_t i = 0; i < tris.size(); i += 3)
+    {
+        check_input(tris[i] < verts_n && tris[i + 1] < verts_n && tris[i + 2] < verts_n, "Triangle index out of range");
+
+        init.tris.push_back({tris[i], tris[i + 1], tris[i + 2]});
+    }
+
+    return std::make_unique<MeshBlob>(std::move(init));
+}
+
+std::unique_ptr<MeshBlob> mesh_shape(const Shape& shape, const MeshParams& params)
+{
+    check_input(!shape.is_null(), "Can't mesh null shape");
+
+    // Mesh a copy, so the caller's shape keeps no triangulation from previous runs
+    return mesh_body(BRepBuilderAPI_Copy(shape.get(), Standard_True, Standard_False).Shape(), params);
+}
+
+} // namespace occt
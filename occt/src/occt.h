@@ -1,14 +1,78 @@
 #pragma once
 
+#include <cstddef>
+#include <cstdint>
+#include <exception>
 #include <memory>
+#include <string>
+#include <vector>
+
+#include "rust/cxx.h"
+
+#include <Standard_Failure.hxx>
+#include <TopoDS_Shape.hxx>
+
+namespace rust {
+namespace behavior {
+
+// Reports OCCT exceptions (not derived from std::exception) as failures
+// instead of letting them escape the bridge
+template <typename Try, typename Fail>
+static void trycatch(Try&& func, Fail&& fail) noexcept
+try
+{
+    func();
+}
+catch(const std::exception& e)
+{
+    fail(e.what());
+}
+catch(const Standard_Failure& e)
+{
+    const std::string message = std::string("failure: ") + e.DynamicType()->Name() + ": " + e.GetMessageString();
+
+    fail(message.c_str());
+}
+catch(...)
+{
+    fail("failure: Unknown exception");
+}
+
+} // namespace behavior
+} // namespace rust
 
 namespace occt {
 
 struct MeshVerts;
 struct MeshNorms;
 struct MeshTris;
+struct MeshFaces;
+struct MeshEdges;
 struct MeshBbox;
 struct MeshBlobInit;
+struct MeshParams;
+struct FlaskParams;
+struct ShapeBbox;
+struct Tuple2d;
+struct SketchSegment;
+struct SweepParams;
+struct SurfaceInfo;
+struct CurveInfo;
+struct UvBounds;
+struct SurfacePoint;
+struct CurvePoint;
+struct DistanceParams;
+struct DistanceData;
+struct Affine3d;
+struct Axes3d;
+struct MassProps;
+struct BooleanOptions;
+enum class BooleanOp : std::uint8_t;
+enum class SketchOp : std::uint8_t;
+enum class SweepMode : std::uint8_t;
+enum class SweepTransition : std::uint8_t;
+enum class ShapeType : std::uint8_t;
+enum class PointState : std::uint8_t;
 
 struct MeshBlob
 {
@@ -22,6 +86,9 @@ struct MeshBlob
     MeshVerts verts() const noexcept;
     MeshNorms norms() const noexcept;
     MeshTris tris() const noexcept;
+    MeshFaces faces() const noexcept;
+    MeshVerts edge_verts() const noexcept;
+    MeshEdges edges() const noexcept;
 
     MeshBlob& operator=(const MeshBlob&) = delete;
 
@@ -31,6 +98,102 @@ private:
     Impl* m_d;
 };
 
-std::unique_ptr<MeshBlob> make_flask(double width, double thickness, double height) noexcept;
+struct Shape
+{
+    Shape() noexcept;
+    explicit Shape(const TopoDS_Shape& shape) noexcept;
+    Shape(const Shape&) = delete;
+
+    ~Shape() noexcept;
+
+    const TopoDS_Shape& get() const noexcept;
+
+    bool is_null() const noexcept;
+    ShapeType shape_type() const noexcept;
+    ShapeBbox bbox() const noexcept;
+
+    Shape& operator=(const Shape&) = delete;
+
+private:
+    TopoDS_Shape m_shape;
+};
+
+struct ShapeList
+{
+    ShapeList() noexcept;
+    ShapeList(const ShapeList&) = delete;
+
+    ~ShapeList() noexcept;
+
+    const std::vector<TopoDS_Shape>& items() const noexcept;
+    std::size_t len() const noexcept;
+    std::unique_ptr<Shape> get(std::size_t index) const;
+
+    void push(const Shape& shape) noexcept;
+    void push(const TopoDS_Shape& shape) noexcept;
+
+    ShapeList& operator=(const ShapeList&) = delete;
+
+private:
+    std::vector<TopoDS_Shape> m_shapes;
+};
+
+std::unique_ptr<ShapeList> new_shape_list() noexcept;
+
+std::unique_ptr<Shape> clone_shape(const Shape& shape) noexcept;
+std::unique_ptr<ShapeList> sub_shapes(const Shape& shape, ShapeType kind) noexcept;
+std::int64_t sub_shape_index(const Shape& shape, const Shape& sub) noexcept;
+rust::Vec<std::uint32_t> related_shapes(const Shape& shape, ShapeType kind, std::uint32_t index, ShapeType related);
+rust::Vec<std::uint32_t> face_neighbors(const Shape& shape, std::uint32_t index);
+SurfaceInfo face_surface(const Shape& face);
+CurveInfo edge_curve(const Shape& edge);
+UvBounds face_uv_bounds(const Shape& face);
+SurfacePoint eval_face(const Shape& face, double u, double v);
+Tuple2d edge_range(const Shape& edge);
+CurvePoint eval_edge(const Shape& edge, double t);
+double edge_length(const Shape& edge);
+std::unique_ptr<Shape> transform_shape(const Shape& shape, const Affine3d& xf);
+std::unique_ptr<MeshBlob> mesh_shape(const Shape& shape, const MeshParams& params);
+std::unique_ptr<MeshBlob> new_mesh_blob(rust::Slice<const float> verts, rust::Slice<const float> norms, rust::Slice<const std::uint32_t> tris);
+std::unique_ptr<Shape> sew_mesh(const MeshBlob& mesh, double tolerance);
+
+std::unique_ptr<Shape> make_box(const Axes3d& axes, double dx, double dy, double dz);
+std::unique_ptr<Shape> make_cylinder(const Axes3d& axes, double radius, double height);
+std::unique_ptr<Shape> make_cone(const Axes3d& axes, double radius1, double radius2, double height);
+std::unique_ptr<Shape> make_sphere(const Axes3d& axes, double radius);
+std::unique_ptr<Shape> make_torus(const Axes3d& axes, double radius1, double radius2);
+std::unique_ptr<Shape> make_wedge(const Axes3d& axes, double dx, double dy, double dz, double ltx);
+
+std::unique_ptr<Shape> make_sketch(const Axes3d& plane, Tuple2d start, rust::Slice<const SketchSegment> segments, rust::Slice<const Tuple2d> points, bool face);
+
+std::unique_ptr<Shape> extrude(const Shape& profile, const Tuple3d& vector);
+std::unique_ptr<Shape> extrude_drafted(const Shape& face, double height, double angle);
+std::unique_ptr<Shape> revolve(const Shape& profile, const Tuple3d& origin, const Tuple3d& axis, double angle);
+std::unique_ptr<Shape> sweep(const Shape& profile, const Shape& spine, const Shape& aux_spine, const SweepParams& params);
+std::unique_ptr<Shape> loft(const ShapeList& sections, bool solid, bool ruled);
+
+std::unique_ptr<Shape> fillet(const Shape& shape, rust::Slice<const std::uint32_t> edges, rust::Slice<const Tuple2d> law);
+std::unique_ptr<Shape> chamfer(const Shape& shape, rust::Slice<const std::uint32_t> edges, double distance1, double distance2);
+
+DistanceData shape_distance(const Shape& shape1, const Shape& shape2, const DistanceParams& params);
+DistanceData point_distance(const Shape& shape, const Tuple3d& point, const DistanceParams& params);
+
+rust::Vec<PointState> classify_points(const Shape& solid, rust::Slice<const Tuple3d> points, double tolerance);
+
+MassProps volume_properties(const Shape& shape);
+MassProps surface_properties(const Shape& shape);
+MassProps linear_properties(const Shape& shape);
+
+void write_brep(const Shape& shape, rust::Str path);
+std::unique_ptr<Shape> read_brep(rust::Str path);
+rust::String write_brep_string(const Shape& shape);
+std::unique_ptr<Shape> read_brep_string(rust::Str text);
+rust::Vec<std::uint8_t> write_brep_bytes(const Shape& shape);
+std::unique_ptr<Shape> read_brep_bytes(rust::Slice<const std::uint8_t> bytes);
+
+std::unique_ptr<Shape> boolean_op(BooleanOp op, const ShapeList& args, const ShapeList& tools, const BooleanOptions& options, rust::Vec<rust::String>& warnings);
+
+std::unique_ptr<MeshBlob> make_flask(const FlaskParams& params, const MeshParams& mesh_params);
+std::unique_ptr<Shape> make_flask_shape(const FlaskParams& params);
 
-} // namespace occt
\ No newline at end of file
+} // namespace occt
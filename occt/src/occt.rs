@@ -7,6 +7,19 @@ pub(crate) mod ffi {
         z: f32
     }
 
+    struct Tuple2d
+    {
+        x: f64,
+        y: f64
+    }
+
+    struct Tuple3d
+    {
+        x: f64,
+        y: f64,
+        z: f64
+    }
+
     struct MeshVerts
     {
         count: u32,
@@ -25,12 +38,301 @@ pub(crate) mod ffi {
         ptr: * const u32
     }
 
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct MeshFace
+    {
+        face: u32,
+        first_tri: u32,
+        tri_count: u32,
+        first_vert: u32,
+        vert_count: u32
+    }
+
+    struct MeshFaces
+    {
+        count: u32,
+        ptr: * const MeshFace
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct MeshEdge
+    {
+        edge: u32,
+        first_vert: u32,
+        vert_count: u32
+    }
+
+    struct MeshEdges
+    {
+        count: u32,
+        ptr: * const MeshEdge
+    }
+
     struct MeshBbox
     {
         min: Tuple3f,
         max: Tuple3f
     }
 
+    struct MeshParams
+    {
+        deflection: f64,
+        angle: f64,
+        deflection_interior: f64,
+        angle_interior: f64,
+        min_size: f64,
+        relative: bool,
+        in_parallel: bool,
+        control_surface_deflection: bool
+    }
+
+    struct FlaskParams
+    {
+        width: f64,
+        thickness: f64,
+        height: f64,
+        wall_thickness: f64,
+        thread: bool,
+        thread_pitch: f64,
+        thread_depth: f64,
+        thread_turns: f64
+    }
+
+    struct ShapeBbox
+    {
+        min: Tuple3d,
+        max: Tuple3d,
+        empty: bool
+    }
+
+    struct Affine3d
+    {
+        x_axis: Tuple3d,
+        y_axis: Tuple3d,
+        z_axis: Tuple3d,
+        translation: Tuple3d
+    }
+
+    struct Axes3d
+    {
+        origin: Tuple3d,
+        dir: Tuple3d,
+        x_dir: Tuple3d
+    }
+
+    struct MassProps
+    {
+        mass: f64,
+        center: Tuple3d,
+        inertia_x: Tuple3d,
+        inertia_y: Tuple3d,
+        inertia_z: Tuple3d,
+        principal_moments: Tuple3d,
+        principal_x: Tuple3d,
+        principal_y: Tuple3d,
+        principal_z: Tuple3d
+    }
+
+    struct BooleanOptions
+    {
+        fuzzy_value: f64,
+        parallel: bool,
+        glue: GlueMode
+    }
+
+    struct SketchSegment
+    {
+        op: SketchOp,
+        first: u32,
+        count: u32
+    }
+
+    enum SketchOp
+    {
+        LineTo,
+        ArcThrough,
+        TangentArc,
+        BSplineThrough,
+        Mirror,
+        Close
+    }
+
+    struct SweepParams
+    {
+        mode: SweepMode,
+        axes: Axes3d,
+        binormal: Tuple3d,
+        curvilinear_equivalence: bool,
+        contact: bool,
+        transition: SweepTransition,
+        solid: bool
+    }
+
+    enum SweepMode
+    {
+        CorrectedFrenet,
+        Frenet,
+        Discrete,
+        Fixed,
+        Binormal,
+        Auxiliary
+    }
+
+    enum SweepTransition
+    {
+        Transformed,
+        RightCorner,
+        RoundCorner
+    }
+
+    struct SurfaceInfo
+    {
+        kind: SurfaceKind,
+        axes: Axes3d,
+        radius: f64,
+        radius2: f64,
+        angle: f64
+    }
+
+    enum SurfaceKind
+    {
+        Plane,
+        Cylinder,
+        Cone,
+        Sphere,
+        Torus,
+        Revolution,
+        Extrusion,
+        Bezier,
+        BSpline,
+        Offset,
+        Other
+    }
+
+    struct CurveInfo
+    {
+        kind: CurveKind,
+        axes: Axes3d,
+        radius: f64,
+        radius2: f64
+    }
+
+    enum CurveKind
+    {
+        Line,
+        Circle,
+        Ellipse,
+        Hyperbola,
+        Parabola,
+        Bezier,
+        BSpline,
+        Offset,
+        Other
+    }
+
+    struct UvBounds
+    {
+        u_min: f64,
+        u_max: f64,
+        v_min: f64,
+        v_max: f64
+    }
+
+    struct SurfacePoint
+    {
+        point: Tuple3d,
+        d1u: Tuple3d,
+        d1v: Tuple3d,
+        d2u: Tuple3d,
+        d2v: Tuple3d,
+        d2uv: Tuple3d,
+        normal: Tuple3d,
+        normal_defined: bool,
+        min_curvature: f64,
+        max_curvature: f64,
+        min_dir: Tuple3d,
+        max_dir: Tuple3d,
+        mean_curvature: f64,
+        gaussian_curvature: f64,
+        curvature_defined: bool
+    }
+
+    struct CurvePoint
+    {
+        point: Tuple3d,
+        d1: Tuple3d,
+        d2: Tuple3d,
+        tangent: Tuple3d,
+        tangent_defined: bool,
+        curvature: f64,
+        normal: Tuple3d,
+        normal_defined: bool
+    }
+
+    struct DistanceParams
+    {
+        deflection: f64,
+        parallel: bool
+    }
+
+    struct DistanceSupport
+    {
+        kind: ShapeType,
+        index: u32,
+        params: Tuple2d
+    }
+
+    struct DistanceSolution
+    {
+        point1: Tuple3d,
+        point2: Tuple3d,
+        support1: DistanceSupport,
+        support2: DistanceSupport
+    }
+
+    struct DistanceData
+    {
+        value: f64,
+        inner: bool,
+        solutions: Vec<DistanceSolution>
+    }
+
+    enum PointState
+    {
+        Inside,
+        Outside,
+        OnBoundary,
+        Unknown
+    }
+
+    enum BooleanOp
+    {
+        Fuse,
+        Cut,
+        Common,
+        Section
+    }
+
+    enum GlueMode
+    {
+        Off,
+        Shift,
+        Full
+    }
+
+    enum ShapeType
+    {
+        Compound,
+        CompSolid,
+        Solid,
+        Shell,
+        Face,
+        Wire,
+        Edge,
+        Vertex,
+        Shape
+    }
+
     unsafe extern "C++" {
         include!("occt/src/occt.h");
 
@@ -40,10 +342,92 @@ pub(crate) mod ffi {
         fn verts(&self) -> MeshVerts;
         fn norms(&self) -> MeshNorms;
         fn tris(&self) -> MeshTris;
+        #[rust_name = "raw_faces"]
+        fn faces(&self) -> MeshFaces;
+        fn edge_verts(&self) -> MeshVerts;
+        #[rust_name = "raw_edges"]
+        fn edges(&self) -> MeshEdges;
+    }
+
+    unsafe extern "C++" {
+        type Shape;
+
+        fn is_null(&self) -> bool;
+        fn shape_type(&self) -> ShapeType;
+        fn bbox(&self) -> ShapeBbox;
+    }
+
+    unsafe extern "C++" {
+        type ShapeList;
 
-        fn make_flask(width: f64, thickness: f64, height: f64) -> UniquePtr<MeshBlob>;
+        fn len(&self) -> usize;
+        fn get(&self, index: usize) -> Result<UniquePtr<Shape>>;
+        fn push(self: Pin<&mut ShapeList>, shape: &Shape);
+    }
+
+    unsafe extern "C++" {
+        fn new_shape_list() -> UniquePtr<ShapeList>;
+
+        fn clone_shape(shape: &Shape) -> UniquePtr<Shape>;
+        fn sub_shapes(shape: &Shape, kind: ShapeType) -> UniquePtr<ShapeList>;
+        fn sub_shape_index(shape: &Shape, sub: &Shape) -> i64;
+        fn related_shapes(shape: &Shape, kind: ShapeType, index: u32, related: ShapeType) -> Result<Vec<u32>>;
+        fn face_neighbors(shape: &Shape, index: u32) -> Result<Vec<u32>>;
+        fn face_surface(face: &Shape) -> Result<SurfaceInfo>;
+        fn edge_curve(edge: &Shape) -> Result<CurveInfo>;
+        fn face_uv_bounds(face: &Shape) -> Result<UvBounds>;
+        fn eval_face(face: &Shape, u: f64, v: f64) -> Result<SurfacePoint>;
+        fn edge_range(edge: &Shape) -> Result<Tuple2d>;
+        fn eval_edge(edge: &Shape, t: f64) -> Result<CurvePoint>;
+        fn edge_length(edge: &Shape) -> Result<f64>;
+        fn transform_shape(shape: &Shape, xf: &Affine3d) -> Result<UniquePtr<Shape>>;
+        fn mesh_shape(shape: &Shape, params: &MeshParams) -> Result<UniquePtr<MeshBlob>>;
+        fn new_mesh_blob(verts: &[f32], norms: &[f32], tris: &[u32]) -> Result<UniquePtr<MeshBlob>>;
+        fn sew_mesh(mesh: &MeshBlob, tolerance: f64) -> Result<UniquePtr<Shape>>;
+
+        fn make_box(axes: &Axes3d, dx: f64, dy: f64, dz: f64) -> Result<UniquePtr<Shape>>;
+        fn make_cylinder(axes: &Axes3d, radius: f64, height: f64) -> Result<UniquePtr<Shape>>;
+        fn make_cone(axes: &Axes3d, radius1: f64, radius2: f64, height: f64) -> Result<UniquePtr<Shape>>;
+        fn make_sphere(axes: &Axes3d, radius: f64) -> Result<UniquePtr<Shape>>;
+        fn make_torus(axes: &Axes3d, radius1: f64, radius2: f64) -> Result<UniquePtr<Shape>>;
+        fn make_wedge(axes: &Axes3d, dx: f64, dy: f64, dz: f64, ltx: f64) -> Result<UniquePtr<Shape>>;
+
+        fn make_sketch(plane: &Axes3d, start: Tuple2d, segments: &[SketchSegment], points: &[Tuple2d], face: bool) -> Result<UniquePtr<Shape>>;
+
+        fn extrude(profile: &Shape, vector: &Tuple3d) -> Result<UniquePtr<Shape>>;
+        fn extrude_drafted(face: &Shape, height: f64, angle: f64) -> Result<UniquePtr<Shape>>;
+        fn revolve(profile: &Shape, origin: &Tuple3d, axis: &Tuple3d, angle: f64) -> Result<UniquePtr<Shape>>;
+        fn sweep(profile: &Shape, spine: &Shape, aux_spine: &Shape, params: &SweepParams) -> Result<UniquePtr<Shape>>;
+        fn loft(sections: &ShapeList, solid: bool, ruled: bool) -> Result<UniquePtr<Shape>>;
+
+        fn fillet(shape: &Shape, edges: &[u32], law: &[Tuple2d]) -> Result<UniquePtr<Shape>>;
+        fn chamfer(shape: &Shape, edges: &[u32], distance1: f64, distance2: f64) -> Result<UniquePtr<Shape>>;
+
+        fn shape_distance(shape1: &Shape, shape2: &Shape, params: &DistanceParams) -> Result<DistanceData>;
+        fn point_distance(shape: &Shape, point: &Tuple3d, params: &DistanceParams) -> Result<DistanceData>;
+
+        fn classify_points(solid: &Shape, points: &[Tuple3d], tolerance: f64) -> Result<Vec<PointState>>;
+
+        fn volume_properties(shape: &Shape) -> Result<MassProps>;
+        fn surface_properties(shape: &Shape) -> Result<MassProps>;
+        fn linear_properties(shape: &Shape) -> Result<MassProps>;
+
+        fn write_brep(shape: &Shape, path: &str) -> Result<()>;
+        fn read_brep(path: &str) -> Result<UniquePtr<Shape>>;
+        fn write_brep_string(shape: &Shape) -> Result<String>;
+        fn read_brep_string(text: &str) -> Result<UniquePtr<Shape>>;
+        fn write_brep_bytes(shape: &Shape) -> Result<Vec<u8>>;
+        fn read_brep_bytes(bytes: &[u8]) -> Result<UniquePtr<Shape>>;
+
+        fn boolean_op(op: BooleanOp, args: &ShapeList, tools: &ShapeList, options: &BooleanOptions, warnings: &mut Vec<String>) -> Result<UniquePtr<Shape>>;
+
+        fn make_flask(params: &FlaskParams, mesh_params: &MeshParams) -> Result<UniquePtr<MeshBlob>>;
+        fn make_flask_shape(params: &FlaskParams) -> Result<UniquePtr<Shape>>;
     }
 }
 
 unsafe impl Sync for ffi::MeshBlob {}
-unsafe impl Send for ffi::MeshBlob {}
\ No newline at end of file
+unsafe impl Send for ffi::MeshBlob {}
+
+unsafe impl Send for ffi::Shape {}
+unsafe impl Send for ffi::ShapeList {}
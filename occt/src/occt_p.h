@@ -0,0 +1,135 @@
+#pragma once
+
+#include "occt/src/occt.h"
+#include "occt/src/occt.rs.h"
+
+#include <stdexcept>
+#include <string>
+
+#include <gp.hxx>
+#include <gp_Ax2.hxx>
+#include <gp_Dir.hxx>
+#include <gp_Pnt.hxx>
+#include <gp_Vec.hxx>
+#include <Precision.hxx>
+#include <TopTools_ListOfShape.hxx>
+
+namespace occt {
+
+// Exception thrown through the bridge, the "<kind>: " prefix is mapped
+// back to ErrorKind on the Rust side
+struct Error : std::runtime_error
+{
+    using std::runtime_error::runtime_error;
+
+    static Error invalid_input(const std::string& message)
+    {
+        return Error("invalid_input: " + message);
+    }
+
+    static Error not_done(const std::string& message)
+    {
+        return Error("not_done: " + message);
+    }
+
+    static Error mesh(const std::string& message)
+    {
+        return Error("mesh: " + message);
+    }
+
+    static Error io(const std::string& message)
+    {
+        return Error("io: " + message);
+    }
+
+    static Error translation(const std::string& message)
+    {
+        return Error("translation: " + message);
+    }
+};
+
+inline void check_input(bool valid, const char* message)
+{
+    if(!valid)
+    {
+        throw Error::invalid_input(message);
+    }
+}
+
+// Builds lazy builders (e.g. BRepPrimAPI_*) and makes sure the result is available
+template <typename Builder>
+const TopoDS_Shape& check_done(Builder& builder, const char* message)
+{
+    if(!builder.IsDone())
+    {
+        builder.Build();
+    }
+
+    if(!builder.IsDone())
+    {
+        throw Error::not_done(message);
+    }
+
+    return builder.Shape();
+}
+
+inline bool is_zero(const Tuple3d& v)
+{
+    return v.x == 0.0 && v.y == 0.0 && v.z == 0.0;
+}
+
+inline gp_Pnt to_pnt(const Tuple3d& v)
+{
+    return gp_Pnt(v.x, v.y, v.z);
+}
+
+inline gp_Vec to_vec(const Tuple3d& v)
+{
+    return gp_Vec(v.x, v.y, v.z);
+}
+
+inline gp_Dir to_dir(const Tuple3d& v)
+{
+    check_input(gp_XYZ(v.x, v.y, v.z).Modulus() > gp::Resolution(), "Direction must be non-zero");
+
+    return gp_Dir(v.x, v.y, v.z);
+}
+
+inline Tuple3d from_xyz(const gp_XYZ& v)
+{
+    return {v.X(), v.Y(), v.Z()};
+}
+
+inline gp_Ax2 to_ax2(const Axes3d& axes)
+{
+    const gp_Dir dir = to_dir(axes.dir);
+
+    // Zero X direction means "pick any", same as gp_Ax2(P, N)
+    if(is_zero(axes.x_dir))
+    {
+        return gp_Ax2(to_pnt(axes.origin), dir);
+    }
+
+    const gp_Dir x_dir = to_dir(axes.x_dir);
+
+    check_input(!dir.IsParallel(x_dir, Precision::Angular()), "Placement X direction must not be parallel to its main direction");
+
+    return gp_Ax2(to_pnt(axes.origin), dir, x_dir);
+}
+
+std::unique_ptr<MeshBlob> mesh_body(TopoDS_Shape body, const MeshParams& params);
+std::vector<TopoDS_Shape> sew_solids(const std::vector<TopoDS_Shape>& shapes, double tolerance);
+
+inline TopTools_ListOfShape to_list(const ShapeList& shapes)
+{
+    TopTools_ListOfShape list;
+
+    for(const TopoDS_Shape& shape : shapes.items())
+    {
+        list.Append(shape);
+    }
+
+    return list;
+}
+
+} // namespace occt
@@ -0,0 +1,65 @@
+use glam::DVec3;
+
+use occt::ffi;
+
+/// Local coordinate system (`gp_Ax2`): origin, main direction and optional X direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    pub origin: DVec3,
+    pub dir: DVec3,
+    pub x_dir: Option<DVec3>,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Self {
+            origin: DVec3::ZERO,
+            dir: DVec3::Z,
+            x_dir: None,
+        }
+    }
+}
+
+impl Placement {
+    pub fn new(origin: DVec3, dir: DVec3) -> Self {
+        Self {
+            origin,
+            dir,
+            x_dir: None,
+        }
+    }
+
+    pub fn at(origin: DVec3) -> Self {
+        Self {
+            origin,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_x_dir(mut self, x_dir: DVec3) -> Self {
+        self.x_dir = Some(x_dir);
+        self
+    }
+
+    pub(crate) fn from_ffi(axes: ffi::Axes3d) -> Self {
+        let x_dir: DVec3 = axes.x_dir.into();
+
+        Self {
+            origin: axes.origin.into(),
+            dir: axes.dir.into(),
+            x_dir: if x_dir == DVec3::ZERO {
+                None
+            } else {
+                Some(x_dir)
+            },
+        }
+    }
+
+    pub(crate) fn to_ffi(self) -> ffi::Axes3d {
+        ffi::Axes3d {
+            origin: self.origin.into(),
+            dir: self.dir.into(),
+            x_dir: self.x_dir.unwrap_or(DVec3::ZERO).into(),
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use error::{ErrorKind, OcctError};
+use mesh::{Color, MeshView};
+use occt::ffi;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlyFormat {
+    #[default]
+    Binary,
+    Ascii,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlyOptions<'a> {
+    pub format: PlyFormat,
+    pub normals: bool,
+    /// Per-vertex colors, same length as `MeshBlob::vertices`.
+    pub colors: Option<&'a [Color]>,
+}
+
+impl Default for PlyOptions<'_> {
+    fn default() -> Self {
+        Self {
+            format: PlyFormat::Binary,
+            normals: true,
+            colors: None,
+        }
+    }
+}
+
+impl ffi::MeshBlob {
+    /// Expands a color per face index into per-vertex colors for `PlyOptions::colors`,
+    /// vertices not owned by any face get `default`.
+    pub fn vertex_colors<F: Fn(usize) -> Color>(
+        &self,
+        face_color: F,
+        default: Color,
+    ) -> Vec<Color> {
+        let mut colors = vec![default; self.vertices().len()];
+
+        for face in self.faces() {
+            let color = face_color(face.face_index());
+
+            colors[face.vertex_range()].fill(color);
+        }
+
+        colors
+    }
+
+    pub fn write_ply<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: &PlyOptions,
+    ) -> Result<(), OcctError> {
+        let mut out = BufWriter::new(File::create(path)?);
+
+        self.write_ply_to(&mut out, options)?;
+
+        Ok(out.flush()?)
+    }
+
+    pub fn write_ply_to<W: Write>(&self, out: W, options: &PlyOptions) -> Result<(), OcctError> {
+        write_ply_mesh(&self.view(), out, options)
+    }
+}
+
+fn write_ply_mesh<W: Write>(
+    mesh: &MeshView,
+    mut out: W,
+    options: &PlyOptions,
+) -> Result<(), OcctError> {
+    let vertices = mesh.vertices;
+    let normals = mesh.normals;
+    let triangles = mesh.triangles;
+
+    if options
+        .colors
+        .is_some_and(|colors| colors.len() != vertices.len())
+    {
+        return Err(OcctError::new(
+            ErrorKind::InvalidInput,
+            "PLY colors must match the vertex count",
+        ));
+    }
+
+    let format = match options.format {
+        PlyFormat::Binary => "binary_little_endian",
+        PlyFormat::Ascii => "ascii",
+    };
+
+    writeln!(out, "ply")?;
+    writeln!(out, "format {} 1.0", format)?;
+    writeln!(out, "comment occt")?;
+    writeln!(out, "element vertex {}", vertices.len())?;
+    writeln!(out, "property float x")?;
+    writeln!(out, "property float y")?;
+    writeln!(out, "property float z")?;
+
+    if options.normals {
+        writeln!(out, "property float nx")?;
+        writeln!(out, "property float ny")?;
+        writeln!(out, "property float nz")?;
+    }
+
+    if options.colors.is_some() {
+        writeln!(out, "property uchar red")?;
+        writeln!(out, "property uchar green")?;
+        writeln!(out, "property uchar blue")?;
+        writeln!(out, "property uchar alpha")?;
+    }
+
+    writeln!(out, "element face {}", triangles.len())?;
+    writeln!(out, "property list uchar uint vertex_indices")?;
+    writeln!(out, "end_header")?;
+
+    for (i, v) in vertices.iter().enumerate() {
+        let mut floats = v.to_vec();
+
+        if options.normals {
+            floats.extend_from_slice(&normals[i]);
+        }
+
+        let color = options
+            .colors
+            .map(|colors| colors[i].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8));
+
+        match options.format {
+            PlyFormat::Binary => {
+                for f in floats {
+                    out.write_all(&f.to_le_bytes())?;
+                }
+
+                if let Some(color) = color {
+                    out.write_all(&color)?;
+                }
+            }
+            PlyFormat::Ascii => {
+                let mut line: Vec<String> = floats.iter().map(|f| f.to_string()).collect();
+
+                if let Some(color) = color {
+                    line.extend(color.iter().map(|c| c.to_string()));
+                }
+
+                writeln!(out, "{}", line.join(" "))?;
+            }
+        }
+    }
+
+    for tri in triangles {
+        match options.format {
+            PlyFormat::Binary => {
+                out.write_all(&[3])?;
+
+                for i in tri {
+                    out.write_all(&i.to_le_bytes())?;
+                }
+            }
+            PlyFormat::Ascii => writeln!(out, "3 {} {} {}", tri[0], tri[1], tri[2])?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mesh::fixture;
+
+    fn write(options: &PlyOptions) -> Result<Vec<u8>, OcctError> {
+        let mut out = Vec::new();
+
+        write_ply_mesh(&fixture::square(&[]), &mut out, options)?;
+
+        Ok(out)
+    }
+
+    fn split_header(data: &[u8]) -> (String, &[u8]) {
+        const END: &[u8] = b"end_header\n";
+
+        let at = data.windows(END.len()).position(|w| w == END).unwrap() + END.len();
+
+        (String::from_utf8(data[..at].to_vec()).unwrap(), &data[at..])
+    }
+
+    #[test]
+    fn header_counts() {
+        let colors = [[1.0, 0.0, 0.0, 1.0]; 4];
+        let data = write(&PlyOptions {
+            format: PlyFormat::Ascii,
+            normals: true,
+            colors: Some(&colors),
+        })
+        .unwrap();
+        let (header, body) = split_header(&data);
+        let body = std::str::from_utf8(body).unwrap();
+
+        assert!(header.starts_with("ply\nformat ascii 1.0\n"));
+        assert!(header.contains("element vertex 4\n"));
+        assert!(header.contains("element face 2\n"));
+        assert_eq!(header.matches("property float").count(), 6);
+        assert_eq!(header.matches("property uchar").count(), 4);
+        assert_eq!(body.lines().count(), 4 + 2);
+        assert_eq!(body.lines().nth(1), Some("1 0 0 0 0 1 255 0 0 255"));
+        assert_eq!(body.lines().nth(4), Some("3 0 1 2"));
+    }
+
+    #[test]
+    fn binary_body_size() {
+        let data = write(&PlyOptions {
+            normals: false,
+            ..Default::default()
+        })
+        .unwrap();
+        let (header, body) = split_header(&data);
+
+        assert!(header.contains("format binary_little_endian 1.0\n"));
+        assert_eq!(header.matches("property float").count(), 3);
+        assert!(!header.contains("property uchar red"));
+        assert_eq!(body.len(), 4 * 12 + 2 * (1 + 3 * 4));
+    }
+
+    #[test]
+    fn rejects_color_count_mismatch() {
+        let colors = [[1.0; 4]; 2];
+        let err = write(&PlyOptions {
+            colors: Some(&colors),
+            ..Default::default()
+        })
+        .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+}
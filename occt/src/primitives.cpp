@@ -0,0 +1,70 @@
+#include "occt/src/occt_p.h"
+
+#include <BRepPrimAPI_MakeBox.hxx>
+#include <BRepPrimAPI_MakeCone.hxx>
+#include <BRepPrimAPI_MakeCylinder.hxx>
+#include <BRepPrimAPI_MakeSphere.hxx>
+#include <BRepPrimAPI_MakeTorus.hxx>
+#include <BRepPrimAPI_MakeWedge.hxx>
+
+namespace occt {
+
+std::unique_ptr<Shape> make_box(const Axes3d& axes, double dx, double dy, double dz)
+{
+    check_input(dx > 0.0 && dy > 0.0 && dz > 0.0, "Box dimensions must be positive");
+
+    BRepPrimAPI_MakeBox mk_box(to_ax2(axes), dx, dy, dz);
+
+    return std::make_unique<Shape>(check_done(mk_box, "Box construction failed"));
+}
+
+std::unique_ptr<Shape> make_cylinder(const Axes3d& axes, double radius, double height)
+{
+    check_input(radius > 0.0, "Cylinder radius must be positive");
+    check_input(height > 0.0, "Cylinder height must be positive");
+
+    BRepPrimAPI_MakeCylinder mk_cylinder(to_ax2(axes), radius, height);
+
+    return std::make_unique<Shape>(check_done(mk_cylinder, "Cylinder construction failed"));
+}
+
+std::unique_ptr<Shape> make_cone(const Axes3d& axes, double radius1, double radius2, double height)
+{
+    check_input(radius1 >= 0.0 && radius2 >= 0.0, "Cone radii must not be negative");
+    check_input(radius1 != radius2, "Cone radii must differ");
+    check_input(height > 0.0, "Cone height must be positive");
+
+    BRepPrimAPI_MakeCone mk_cone(to_ax2(axes), radius1, radius2, height);
+
+    return std::make_unique<Shape>(check_done(mk_cone, "Cone construction failed"));
+}
+
+std::unique_ptr<Shape> make_sphere(const Axes3d& axes, double radius)
+{
+    check_input(radius > 0.0, "Sphere radius must be positive");
+
+    BRepPrimAPI_MakeSphere mk_sphere(to_ax2(axes), radius);
+
+    return std::make_unique<Shape>(check_done(mk_sphere, "Sphere construction failed"));
+}
+
+std::unique_ptr<Shape> make_torus(const Axes3d& axes, double radius1, double radius2)
+{
+    check_input(radius1 > 0.0 && radius2 > 0.0, "Torus radii must be positive");
+
+    BRepPrimAPI_MakeTorus mk_torus(to_ax2(axes), radius1, radius2);
+
+    return std::make_unique<Shape>(check_done(mk_torus, "Torus construction failed"));
+}
+
+std::unique_ptr<Shape> make_wedge(const Axes3d& axes, double dx, double dy, double dz, double ltx)
+{
+    check_input(dx > 0.0 && dy > 0.0 && dz > 0.0, "Wedge dimensions must be positive");
+    check_input(ltx >= 0.0, "Wedge top length must not be negative");
+
+    BRepPrimAPI_MakeWedge mk_wedge(to_ax2(axes), dx, dy, dz, ltx);
+
+    return std::make_unique<Shape>(check_done(mk_wedge, "Wedge construction failed"));
+}
+
+} // namespace occt
@@ -0,0 +1,84 @@
+use error::OcctError;
+use occt::ffi;
+use placement::Placement;
+use shape::Shape;
+
+fn axes(placement: Option<&Placement>) -> ffi::Axes3d {
+    placement.copied().unwrap_or_default().to_ffi()
+}
+
+/// Box with a corner at the placement origin.
+pub fn make_box(
+    dx: f64,
+    dy: f64,
+    dz: f64,
+    placement: Option<&Placement>,
+) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::make_box(
+        &axes(placement),
+        dx,
+        dy,
+        dz,
+    )?))
+}
+
+pub fn make_cylinder(
+    radius: f64,
+    height: f64,
+    placement: Option<&Placement>,
+) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::make_cylinder(
+        &axes(placement),
+        radius,
+        height,
+    )?))
+}
+
+/// Cone or truncated cone, one of the radii may be zero.
+pub fn make_cone(
+    radius1: f64,
+    radius2: f64,
+    height: f64,
+    placement: Option<&Placement>,
+) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::make_cone(
+        &axes(placement),
+        radius1,
+        radius2,
+        height,
+    )?))
+}
+
+pub fn make_sphere(radius: f64, placement: Option<&Placement>) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::make_sphere(&axes(placement), radius)?))
+}
+
+/// Torus with major radius `radius1` and minor radius `radius2`.
+pub fn make_torus(
+    radius1: f64,
+    radius2: f64,
+    placement: Option<&Placement>,
+) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::make_torus(
+        &axes(placement),
+        radius1,
+        radius2,
+    )?))
+}
+
+/// Right angular wedge, `ltx` is the X length of the face at `dy`.
+pub fn make_wedge(
+    dx: f64,
+    dy: f64,
+    dz: f64,
+    ltx: f64,
+    placement: Option<&Placement>,
+) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::make_wedge(
+        &axes(placement),
+        dx,
+        dy,
+        dz,
+        ltx,
+    )?))
+}
@@ -0,0 +1,67 @@
+#include "occt/src/occt_p.h"
+
+#include <BRepGProp.hxx>
+#include <GProp_GProps.hxx>
+#include <GProp_PrincipalProps.hxx>
+#include <gp_Mat.hxx>
+
+namespace occt {
+
+namespace {
+
+MassProps to_mass_props(const GProp_GProps& props)
+{
+    const gp_Mat inertia = props.MatrixOfInertia();
+    const GProp_PrincipalProps principal = props.PrincipalProperties();
+
+    MassProps raw;
+
+    raw.mass = props.Mass();
+    raw.center = from_xyz(props.CentreOfMass().XYZ());
+    raw.inertia_x = from_xyz(inertia.Column(1));
+    raw.inertia_y = from_xyz(inertia.Column(2));
+    raw.inertia_z = from_xyz(inertia.Column(3));
+    principal.Moments(raw.principal_moments.x, raw.principal_moments.y, raw.principal_moments.z);
+    raw.principal_x = from_xyz(principal.FirstAxisOfInertia().XYZ());
+    raw.principal_y = from_xyz(principal.SecondAxisOfInertia().XYZ());
+    raw.principal_z = from_xyz(principal.ThirdAxisOfInertia().XYZ());
+
+    return raw;
+}
+
+} // namespace
+
+MassProps volume_properties(const Shape& shape)
+{
+    check_input(!shape.is_null(), "Can't compute properties of null shape");
+
+    GProp_GProps props;
+
+    BRepGProp::VolumeProperties(shape.get(), props);
+
+    return to_mass_props(props);
+}
+
+MassProps surface_properties(const Shape& shape)
+{
+    check_input(!shape.is_null(), "Can't compute properties of null shape");
+
+    GProp_GProps props;
+
+    BRepGProp::SurfaceProperties(shape.get(), props);
+
+    return to_mass_props(props);
+}
+
+MassProps linear_properties(const Shape& shape)
+{
+    check_input(!shape.is_null(), "Can't compute properties of null shape");
+
+    GProp_GProps props;
+
+    BRepGProp::LinearProperties(shape.get(), props);
+
+    return to_mass_props(props);
+}
+
+} // namespace occt
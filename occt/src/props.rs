@@ -0,0 +1,65 @@
+use glam::{DMat3, DVec3};
+
+use error::OcctError;
+use occt::ffi;
+use shape::Shape;
+
+/// Global properties of a shape (`GProp_GProps`) assuming unit density.
+///
+/// Mass is the volume, area or length depending on the query.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MassProperties {
+    pub mass: f64,
+    pub center_of_mass: DVec3,
+    /// Inertia matrix about the center of mass.
+    pub inertia: DMat3,
+    pub principal_moments: DVec3,
+    /// Principal axes of inertia as matrix columns, matching `principal_moments`.
+    pub principal_axes: DMat3,
+}
+
+impl From<ffi::MassProps> for MassProperties {
+    fn from(raw: ffi::MassProps) -> Self {
+        Self {
+            mass: raw.mass,
+            center_of_mass: raw.center.into(),
+            inertia: DMat3::from_cols(
+                raw.inertia_x.into(),
+                raw.inertia_y.into(),
+                raw.inertia_z.into(),
+            ),
+            principal_moments: raw.principal_moments.into(),
+            principal_axes: DMat3::from_cols(
+                raw.principal_x.into(),
+                raw.principal_y.into(),
+                raw.principal_z.into(),
+            ),
+        }
+    }
+}
+
+impl MassProperties {
+    /// Scales mass and inertia by a material density.
+    pub fn with_density(&self, density: f64) -> Self {
+        Self {
+            mass: self.mass * density,
+            inertia: self.inertia * density,
+            principal_moments: self.principal_moments * density,
+            ..*self
+        }
+    }
+}
+
+impl Shape {
+    pub fn volume_properties(&self) -> Result<MassProperties, OcctError> {
+        Ok(ffi::volume_properties(self.as_ffi())?.into())
+    }
+
+    pub fn surface_properties(&self) -> Result<MassProperties, OcctError> {
+        Ok(ffi::surface_properties(self.as_ffi())?.into())
+    }
+
+    pub fn linear_properties(&self) -> Result<MassProperties, OcctError> {
+        Ok(ffi::linear_properties(self.as_ffi())?.into())
+    }
+}
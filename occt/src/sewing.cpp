@@ -0,0 +1,149 @@
+#include "occt/src/occt_p.h"
+
+#include <vector>
+
+#include <BRep_Builder.hxx>
+#include <BRep_Tool.hxx>
+#include <BRepBuilderAPI_MakeFace.hxx>
+#include <BRepBuilderAPI_MakePolygon.hxx>
+#include <BRepBuilderAPI_Sewing.hxx>
+#include <ShapeFix_Solid.hxx>
+#include <TopExp_Explorer.hxx>
+#include <TopoDS.hxx>
+#include <TopoDS_Compound.hxx>
+
+namespace occt {
+
+// Joins faces along shared boundaries, closed shells become solids
+std::vector<TopoDS_Shape> sew_solids(const std::vector<TopoDS_Shape>& shapes, double tolerance)
+{
+    std::vector<TopoDS_Shape> result;
+    BRepBuilderAPI_Sewing sewing(tolerance);
+    bool has_faces = false;
+
+    for(const TopoDS_Shape& shape : shapes)
+    {
+        // Wireframe entities have nothing to sew
+        if(TopExp_Explorer(shape, TopAbs_FACE).More())
+        {
+            sewing.Add(shape);
+            has_faces = true;
+        }
+        else
+        {
+            result.push_back(shape);
+        }
+    }
+
+    if(!has_faces)
+    {
+        return result;
+    }
+
+    sewing.Perform();
+
+    const TopoDS_Shape sewed = sewing.SewedShape();
+
+    if(sewed.IsNull())
+    {
+        throw Error::not_done("Sewing failed");
+    }
+
+    for(TopExp_Explorer it(sewed, TopAbs_SHELL); it.More(); it.Next())
+    {
+        const TopoDS_Shell& shell = TopoDS::Shell(it.Current());
+
+        if(BRep_Tool::IsClosed(shell))
+        {
+            ShapeFix_Solid fix_solid;
+
+            result.push_back(fix_solid.SolidFromShell(shell));
+        }
+        else
+        {
+            result.push_back(shell);
+        }
+    }
+
+    // Faces that did not join any shell
+    for(TopExp_Explorer it(sewed, TopAbs_FACE, TopAbs_SHELL); it.More(); it.Next())
+    {
+        result.push_back(it.Current());
+    }
+
+    return result;
+}
+
+std::unique_ptr<Shape> sew_mesh(const MeshBlob& mesh, double tolerance)
+{
+    check_input(tolerance > 0.0, "Sewing tolerance must be positive");
+
+    const MeshVerts verts = mesh.verts();
+    const MeshTris tris = mesh.tris();
+
+    check_input(tris.count > 0, "Mesh has no triangles");
+
+    auto vertex = [&verts](std::uint32_t idx)
+    {
+        const float* v = verts.ptr + 3 * idx;
+
+        return gp_Pnt(v[0], v[1], v[2]);
+    };
+
+    std::vector<TopoDS_Shape> faces;
+
+    faces.reserve(tris.count);
+
+    for(std::uint32_t i = 0; i < tris.count; ++i)
+    {
+        const gp_Pnt a = vertex(tris.ptr[3 * i]);
+        const gp_Pnt b = vertex(tris.ptr[3 * i + 1]);
+        const gp_Pnt c = vertex(tris.ptr[3 * i + 2]);
+
+        // Slivers can't bound a planar face
+        if(gp_Vec(a, b).Crossed(gp_Vec(a, c)).Magnitude() <= tolerance * tolerance)
+        {
+            continue;
+        }
+
+        BRepBuilderAPI_MakePolygon mk_polygon(a, b, c, Standard_True);
+
+        if(!mk_polygon.IsDone())
+        {
+            continue;
+        }
+
+        BRepBuilderAPI_MakeFace mk_face(mk_polygon.Wire(), Standard_True);
+
+        if(mk_face.IsDone())
+        {
+            faces.push_back(mk_face.Face());
+        }
+    }
+
+    if(faces.empty())
+    {
+        throw Error::not_done("Mesh has only degenerated triangles");
+    }
+
+    const std::vector<TopoDS_Shape> sewed = sew_solids(faces, tolerance);
+
+    if(sewed.size() == 1)
+    {
+        return std::make_unique<Shape>(sewed.front());
+    }
+
+    BRep_Builder builder;
+    TopoDS_Compound compound;
+
+    builder.MakeCompound(compound);
+
+    for(const TopoDS_Shape& shape : sewed)
+    {
+        builder.Add(compound, shape);
+    }
+
+    return std::make_unique<Shape>(compound);
+}
+
+} // namespace occt
@@ -0,0 +1,171 @@
+#include "occt/src/occt_p.h"
+
+#include <cmath>
+
+#include <Bnd_Box.hxx>
+#include <BRepBndLib.hxx>
+#include <BRepBuilderAPI_Transform.hxx>
+#include <gp_Mat.hxx>
+#include <gp_Trsf.hxx>
+
+namespace occt {
+
+Shape::Shape() noexcept = default;
+
+Shape::Shape(const TopoDS_Shape& shape) noexcept
+  : m_shape(shape)
+{
+}
+
+Shape::~Shape() noexcept = default;
+
+const TopoDS_Shape& Shape::get() const noexcept
+{
+    return m_shape;
+}
+
+bool Shape::is_null() const noexcept
+{
+    return m_shape.IsNull();
+}
+
+ShapeType Shape::shape_type() const noexcept
+{
+    if(m_shape.IsNull())
+    {
+        return ShapeType::Shape;
+    }
+
+    switch(m_shape.ShapeType())
+    {
+    case TopAbs_COMPOUND:
+        return ShapeType::Compound;
+    case TopAbs_COMPSOLID:
+        return ShapeType::CompSolid;
+    case TopAbs_SOLID:
+        return ShapeType::Solid;
+    case TopAbs_SHELL:
+        return ShapeType::Shell;
+    case TopAbs_FACE:
+        return ShapeType::Face;
+    case TopAbs_WIRE:
+        return ShapeType::Wire;
+    case TopAbs_EDGE:
+        return ShapeType::Edge;
+    case TopAbs_VERTEX:
+        return ShapeType::Vertex;
+    default:
+        return ShapeType::Shape;
+    }
+}
+
+ShapeBbox Shape::bbox() const noexcept
+{
+    ShapeBbox raw;
+
+    raw.min = {0.0, 0.0, 0.0};
+    raw.max = {0.0, 0.0, 0.0};
+    raw.empty = true;
+
+    if(m_shape.IsNull())
+    {
+        return raw;
+    }
+
+    Bnd_Box box;
+
+    BRepBndLib::AddOptimal(m_shape, box, Standard_False, Standard_False);
+
+    if(box.IsVoid())
+    {
+        return raw;
+    }
+
+    box.Get(raw.min.x, raw.min.y, raw.min.z, raw.max.x, raw.max.y, raw.max.z);
+    raw.empty = false;
+
+    return raw;
+}
+
+ShapeList::ShapeList() noexcept = default;
+
+ShapeList::~ShapeList() noexcept = default;
+
+const std::vector<TopoDS_Shape>& ShapeList::items() const noexcept
+{
+    return m_shapes;
+}
+
+std::size_t ShapeList::len() const noexcept
+{
+    return m_shapes.size();
+}
+
+std::unique_ptr<Shape> ShapeList::get(std::size_t index) const
+{
+    check_input(index < m_shapes.size(), "Shape list index out of range");
+
+    return std::make_unique<Shape>(m_shapes[index]);
+}
+
+void ShapeList::push(const Shape& shape) noexcept
+{
+    m_shapes.push_back(shape.get());
+}
+
+void ShapeList::push(const TopoDS_Shape& shape) noexcept
+{
+    m_shapes.push_back(shape);
+}
+
+std::unique_ptr<ShapeList> new_shape_list() noexcept
+{
+    return std::make_unique<ShapeList>();
+}
+
+std::unique_ptr<Shape> clone_shape(const Shape& shape) noexcept
+{
+    // TopoDS_Shape copies share the underlying B-rep, nothing in the bridge
+    // modifies a shape in place
+    return std::make_unique<Shape>(shape.get());
+}
+
+std::unique_ptr<Shape> transform_shape(const Shape& shape, const Affine3d& xf)
+{
+    check_input(!shape.is_null(), "Can't transform null shape");
+
+    // gp_Trsf only represents rigid motions with uniform scale
+    {
+        const gp_Mat m(gp_XYZ(xf.x_axis.x, xf.x_axis.y, xf.x_axis.z),
+            gp_XYZ(xf.y_axis.x, xf.y_axis.y, xf.y_axis.z),
+            gp_XYZ(xf.z_axis.x, xf.z_axis.y, xf.z_axis.z));
+        const double det = m.Determinant();
+
+        check_input(std::abs(det) > gp::Resolution(), "Transformation is degenerate");
+
+        const double scale2 = std::pow(std::abs(det), 2.0 / 3.0);
+        const gp_Mat mtm = m.Transposed() * m;
+
+        for(int r = 1; r <= 3; ++r)
+        {
+            for(int c = 1; c <= 3; ++c)
+            {
+                const double expected = r == c ? scale2 : 0.0;
+
+                check_input(std::abs(mtm(r, c) - expected) <= 1e-9 * scale2, "Transformation must be a rigid motion with uniform scale");
+            }
+        }
+    }
+
+    gp_Trsf trsf;
+
+    trsf.SetValues(xf.x_axis.x, xf.y_axis.x, xf.z_axis.x, xf.translation.x,
+        xf.x_axis.y, xf.y_axis.y, xf.z_axis.y, xf.translation.y,
+        xf.x_axis.z, xf.y_axis.z, xf.z_axis.z, xf.translation.z);
+
+    BRepBuilderAPI_Transform mk_transform(shape.get(), trsf, Standard_True);
+
+    return std::make_unique<Shape>(check_done(mk_transform, "Transformation failed"));
+}
+
+} // namespace occt
@@ -0,0 +1,152 @@
+use cxx::UniquePtr;
+use glam::{DAffine3, DQuat, DVec3};
+
+use error::OcctError;
+use occt::ffi;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShapeType {
+    Compound,
+    CompSolid,
+    Solid,
+    Shell,
+    Face,
+    Wire,
+    Edge,
+    Vertex,
+    Shape,
+}
+
+impl From<ffi::ShapeType> for ShapeType {
+    fn from(ty: ffi::ShapeType) -> Self {
+        match ty {
+            ffi::ShapeType::Compound => ShapeType::Compound,
+            ffi::ShapeType::CompSolid => ShapeType::CompSolid,
+            ffi::ShapeType::Solid => ShapeType::Solid,
+            ffi::ShapeType::Shell => ShapeType::Shell,
+            ffi::ShapeType::Face => ShapeType::Face,
+            ffi::ShapeType::Wire => ShapeType::Wire,
+            ffi::ShapeType::Edge => ShapeType::Edge,
+            ffi::ShapeType::Vertex => ShapeType::Vertex,
+            _ => ShapeType::Shape,
+        }
+    }
+}
+
+impl From<ShapeType> for ffi::ShapeType {
+    fn from(ty: ShapeType) -> Self {
+        match ty {
+            ShapeType::Compound => ffi::ShapeType::Compound,
+            ShapeType::CompSolid => ffi::ShapeType::CompSolid,
+            ShapeType::Solid => ffi::ShapeType::Solid,
+            ShapeType::Shell => ffi::ShapeType::Shell,
+            ShapeType::Face => ffi::ShapeType::Face,
+            ShapeType::Wire => ffi::ShapeType::Wire,
+            ShapeType::Edge => ffi::ShapeType::Edge,
+            ShapeType::Vertex => ffi::ShapeType::Vertex,
+            ShapeType::Shape => ffi::ShapeType::Shape,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    pub min: DVec3,
+    pub max: DVec3,
+}
+
+/// B-rep shape (`TopoDS_Shape`) owned on the Rust side.
+///
+/// Cloning is cheap: clones share the underlying B-rep, which the bridge
+/// never modifies in place.
+pub struct Shape {
+    inner: UniquePtr<ffi::Shape>,
+}
+
+impl Clone for Shape {
+    fn clone(&self) -> Self {
+        Self::from_ffi(ffi::clone_shape(self.as_ffi()))
+    }
+}
+
+impl Shape {
+    pub(crate) fn from_ffi(inner: UniquePtr<ffi::Shape>) -> Self {
+        debug_assert!(!inner.is_null());
+
+        Self { inner }
+    }
+
+    pub(crate) fn as_ffi(&self) -> &ffi::Shape {
+        &self.inner
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.as_ffi().is_null()
+    }
+
+    pub fn shape_type(&self) -> ShapeType {
+        self.as_ffi().shape_type().into()
+    }
+
+    /// Tight axis aligned bounds, `None` for null or empty shapes.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let bbox = self.as_ffi().bbox();
+
+        if bbox.empty {
+            return None;
+        }
+
+        Some(BoundingBox {
+            min: bbox.min.into(),
+            max: bbox.max.into(),
+        })
+    }
+
+    /// Returns a transformed copy, `xf` must be a rigid motion with optional uniform scale.
+    pub fn transformed(&self, xf: &DAffine3) -> Result<Shape, OcctError> {
+        let raw = ffi::Affine3d {
+            x_axis: xf.matrix3.x_axis.into(),
+            y_axis: xf.matrix3.y_axis.into(),
+            z_axis: xf.matrix3.z_axis.into(),
+            translation: xf.translation.into(),
+        };
+
+        Ok(Self::from_ffi(ffi::transform_shape(self.as_ffi(), &raw)?))
+    }
+
+    pub fn translated(&self, offset: DVec3) -> Result<Shape, OcctError> {
+        self.transformed(&DAffine3::from_translation(offset))
+    }
+
+    pub fn rotated(&self, origin: DVec3, axis: DVec3, angle: f64) -> Result<Shape, OcctError> {
+        let rot = DAffine3::from_quat(DQuat::from_axis_angle(axis.normalize(), angle));
+
+        self.transformed(
+            &(DAffine3::from_translation(origin) * rot * DAffine3::from_translation(-origin)),
+        )
+    }
+
+    pub fn scaled(&self, center: DVec3, factor: f64) -> Result<Shape, OcctError> {
+        let scale = DAffine3::from_scale(DVec3::splat(factor));
+
+        self.transformed(
+            &(DAffine3::from_translation(center) * scale * DAffine3::from_translation(-center)),
+        )
+    }
+}
+
+impl From<DVec3> for ffi::Tuple3d {
+    fn from(v: DVec3) -> Self {
+        ffi::Tuple3d {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<ffi::Tuple3d> for DVec3 {
+    fn from(v: ffi::Tuple3d) -> Self {
+        DVec3::new(v.x, v.y, v.z)
+    }
+}
@@ -0,0 +1,191 @@
+#include "occt/src/occt_p.h"
+
+#include <vector>
+
+#include <BRep_Tool.hxx>
+#include <BRepBuilderAPI_MakeEdge.hxx>
+#include <BRepBuilderAPI_MakeFace.hxx>
+#include <BRepBuilderAPI_MakeWire.hxx>
+#include <GCE2d_MakeArcOfCircle.hxx>
+#include <GCE2d_MakeSegment.hxx>
+#include <Geom2d_Curve.hxx>
+#include <Geom2dAPI_Interpolate.hxx>
+#include <GeomAPI.hxx>
+#include <gp_Ax2d.hxx>
+#include <gp_Ax3.hxx>
+#include <gp_Dir2d.hxx>
+#include <gp_Pln.hxx>
+#include <gp_Pnt2d.hxx>
+#include <gp_Vec2d.hxx>
+#include <TColgp_HArray1OfPnt2d.hxx>
+
+namespace occt {
+
+namespace {
+
+gp_Pnt2d to_pnt2d(const Tuple2d& v)
+{
+    return gp_Pnt2d(v.x, v.y);
+}
+
+// Chains 2D curves in plane coordinates, each one starts where the previous ended
+std::vector<Handle(Geom2d_Curve)> build_curves(const gp_Pnt2d& first, rust::Slice<const SketchSegment> segments, rust::Slice<const Tuple2d> points, bool& closed)
+{
+    std::vector<Handle(Geom2d_Curve)> curves;
+    gp_Pnt2d current = first;
+
+    auto point = [&points](std::size_t idx)
+    {
+        return to_pnt2d(points[idx]);
+    };
+    auto push = [&curves, &current](const Handle(Geom2d_Curve)& curve)
+    {
+        curves.push_back(curve);
+        current = curve->Value(curve->LastParameter());
+    };
+
+    closed = false;
+
+    for(const SketchSegment& seg : segments)
+    {
+        check_input(!closed, "Sketch is already closed");
+        check_input(std::size_t(seg.first) + seg.count <= points.size(), "Sketch segment points out of range");
+
+        switch(seg.op)
+        {
+        case SketchOp::LineTo:
+        {
+            GCE2d_MakeSegment mk_segment(current, point(seg.first));
+
+            check_input(mk_segment.IsDone(), "Sketch line has zero length");
+            push(mk_segment.Value());
+            break;
+        }
+        case SketchOp::ArcThrough:
+        {
+            GCE2d_MakeArcOfCircle mk_arc(current, point(seg.first), point(seg.first + 1));
+
+            check_input(mk_arc.IsDone(), "Sketch arc points are collinear or coincident");
+            push(mk_arc.Value());
+            break;
+        }
+        case SketchOp::TangentArc:
+        {
+            check_input(!curves.empty(), "Tangent arc needs a previous segment");
+
+            gp_Pnt2d end;
+            gp_Vec2d tangent;
+
+            curves.back()->D1(curves.back()->LastParameter(), end, tangent);
+
+            GCE2d_MakeArcOfCircle mk_arc(current, tangent, point(seg.first));
+
+            check_input(mk_arc.IsDone(), "Tangent arc end lies on the previous tangent");
+            push(mk_arc.Value());
+            break;
+        }
+        case SketchOp::BSplineThrough:
+        {
+            check_input(seg.count > 0, "BSpline needs at least one point");
+
+            Handle(TColgp_HArray1OfPnt2d) poles = new TColgp_HArray1OfPnt2d(1, static_cast<int>(seg.count) + 1);
+
+            poles->SetValue(1, current);
+
+            for(std::uint32_t i = 0; i < seg.count; ++i)
+            {
+                poles->SetValue(static_cast<int>(i) + 2, point(seg.first + i));
+            }
+
+            Geom2dAPI_Interpolate interpolate(poles, Standard_False, Precision::Confusion());
+
+            interpolate.Perform();
+
+            if(!interpolate.IsDone())
+            {
+                throw Error::not_done("BSpline interpolation failed");
+            }
+
+            push(interpolate.Curve());
+            break;
+        }
+        case SketchOp::Mirror:
+        {
+            check_input(!curves.empty(), "Nothing to mirror");
+
+            const Tuple2d& dir = points[seg.first + 1];
+
+            check_input(gp_Vec2d(dir.x, dir.y).Magnitude() > gp::Resolution(), "Mirror axis direction must be non-zero");
+
+            const gp_Ax2d axis(point(seg.first), gp_Dir2d(dir.x, dir.y));
+
+            // Walk the mirrored copy backwards so the chain continues from the axis
+            for(std::size_t i = curves.size(); i-- > 0;)
+            {
+                push(Handle(Geom2d_Curve)::DownCast(curves[i]->Mirrored(axis))->Reversed());
+            }
+
+            break;
+        }
+        case SketchOp::Close:
+        {
+            if(!current.IsEqual(first, Precision::Confusion()))
+            {
+                push(GCE2d_MakeSegment(current, first).Value());
+            }
+
+            closed = true;
+            break;
+        }
+        default:
+            throw Error::invalid_input("Unknown sketch operation");
+        }
+    }
+
+    check_input(!curves.empty(), "Sketch has no segments");
+
+    return curves;
+}
+
+} // namespace
+
+std::unique_ptr<Shape> make_sketch(const Axes3d& plane, Tuple2d start, rust::Slice<const SketchSegment> segments, rust::Slice<const Tuple2d> points, bool face)
+{
+    const gp_Pln pln{gp_Ax3(to_ax2(plane))};
+    bool closed = false;
+    const std::vector<Handle(Geom2d_Curve)> curves = build_curves(to_pnt2d(start), segments, points, closed);
+
+    BRepBuilderAPI_MakeWire mk_wire;
+
+    for(const Handle(Geom2d_Curve)& curve : curves)
+    {
+        BRepBuilderAPI_MakeEdge mk_edge(GeomAPI::To3d(curve, pln));
+
+        if(!mk_edge.IsDone())
+        {
+            throw Error::not_done("Failed to build sketch edge");
+        }
+
+        mk_wire.Add(mk_edge.Edge());
+
+        if(!mk_wire.IsDone())
+        {
+            throw Error::invalid_input("Sketch segments are not connected");
+        }
+    }
+
+    const TopoDS_Wire wire = mk_wire.Wire();
+
+    if(!face)
+    {
+        return std::make_unique<Shape>(wire);
+    }
+
+    check_input(closed || BRep_Tool::IsClosed(wire), "Only closed sketches can become faces");
+
+    BRepBuilderAPI_MakeFace mk_face(pln, wire, Standard_True);
+
+    return std::make_unique<Shape>(check_done(mk_face, "Failed to build sketch face"));
+}
+
+} // namespace occt
@@ -0,0 +1,130 @@
+use glam::DVec2;
+
+use error::OcctError;
+use occt::ffi;
+use placement::Placement;
+use shape::Shape;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    LineTo(DVec2),
+    ArcThrough(DVec2, DVec2),
+    TangentArc(DVec2),
+    BSplineThrough(Vec<DVec2>),
+    Mirror(DVec2, DVec2),
+    Close,
+}
+
+/// Planar profile made of connected segments, coordinates are in the plane's
+/// X/Y axes.
+///
+/// Segments are validated when the wire or face is built.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sketch {
+    plane: Placement,
+    start: DVec2,
+    segments: Vec<Segment>,
+}
+
+impl Sketch {
+    /// Starts a profile at `start` on the XY plane.
+    pub fn new(start: DVec2) -> Self {
+        Self {
+            plane: Placement::default(),
+            start,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Plane the profile lies in, `dir` is the normal.
+    pub fn with_plane(mut self, plane: Placement) -> Self {
+        self.plane = plane;
+        self
+    }
+
+    pub fn line_to(mut self, end: DVec2) -> Self {
+        self.segments.push(Segment::LineTo(end));
+        self
+    }
+
+    /// Circular arc from the current point through `mid` to `end`.
+    pub fn arc_through(mut self, mid: DVec2, end: DVec2) -> Self {
+        self.segments.push(Segment::ArcThrough(mid, end));
+        self
+    }
+
+    /// Circular arc to `end` continuing the tangent of the previous segment.
+    pub fn tangent_arc(mut self, end: DVec2) -> Self {
+        self.segments.push(Segment::TangentArc(end));
+        self
+    }
+
+    /// Interpolating BSpline from the current point through `points`.
+    pub fn bspline_through(mut self, points: &[DVec2]) -> Self {
+        self.segments.push(Segment::BSplineThrough(points.to_vec()));
+        self
+    }
+
+    /// Appends the mirror image of all segments so far about the axis through
+    /// `origin` along `dir`, walked backwards. The current point should lie on
+    /// the axis, e.g. to complete a symmetric half profile.
+    pub fn mirror(mut self, origin: DVec2, dir: DVec2) -> Self {
+        self.segments.push(Segment::Mirror(origin, dir));
+        self
+    }
+
+    /// Closes the profile with a line back to the start if needed.
+    pub fn close(mut self) -> Self {
+        self.segments.push(Segment::Close);
+        self
+    }
+
+    pub fn to_wire(&self) -> Result<Shape, OcctError> {
+        self.build(false)
+    }
+
+    /// Planar face bounded by the profile, which must be closed.
+    pub fn to_face(&self) -> Result<Shape, OcctError> {
+        self.build(true)
+    }
+
+    fn build(&self, face: bool) -> Result<Shape, OcctError> {
+        let mut segments = Vec::with_capacity(self.segments.len());
+        let mut points = Vec::new();
+
+        for segment in &self.segments {
+            let first = points.len() as u32;
+            let (op, segment_points) = match segment {
+                Segment::LineTo(end) => (ffi::SketchOp::LineTo, vec![*end]),
+                Segment::ArcThrough(mid, end) => (ffi::SketchOp::ArcThrough, vec![*mid, *end]),
+                Segment::TangentArc(end) => (ffi::SketchOp::TangentArc, vec![*end]),
+                Segment::BSplineThrough(through) => {
+                    (ffi::SketchOp::BSplineThrough, through.clone())
+                }
+                Segment::Mirror(origin, dir) => (ffi::SketchOp::Mirror, vec![*origin, *dir]),
+                Segment::Close => (ffi::SketchOp::Close, vec![]),
+            };
+
+            points.extend(segment_points.into_iter().map(ffi::Tuple2d::from));
+            segments.push(ffi::SketchSegment {
+                op,
+                first,
+                count: points.len() as u32 - first,
+            });
+        }
+
+        Ok(Shape::from_ffi(ffi::make_sketch(
+            &self.plane.to_ffi(),
+            self.start.into(),
+            &segments,
+            &points,
+            face,
+        )?))
+    }
+}
+
+impl From<DVec2> for ffi::Tuple2d {
+    fn from(v: DVec2) -> Self {
+        ffi::Tuple2d { x: v.x, y: v.y }
+    }
+}
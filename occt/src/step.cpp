@@ -0,0 +1,94 @@
+#include "occt/src/exchange_p.h"
+#include "occt/src/step.h"
+#include "occt/src/step.rs.h"
+
+#include <IFSelect_ReturnStatus.hxx>
+#include <Interface_Static.hxx>
+#include <STEPControl_Controller.hxx>
+#include <STEPControl_Reader.hxx>
+#include <STEPControl_Writer.hxx>
+
+namespace occt {
+
+namespace {
+
+// Values of the "write.step.schema" parameter
+int schema_id(StepSchema schema)
+{
+    switch(schema)
+    {
+    case StepSchema::Ap203:
+        return 3;
+    case StepSchema::Ap242:
+        return 5;
+    case StepSchema::Ap214:
+    default:
+        return 4;
+    }
+}
+
+} // namespace
+
+std::unique_ptr<ShapeList> read_step(rust::Str path, double unit_scale)
+{
+    check_input(unit_scale > 0.0, "Unit scale must be positive");
+
+    const std::string file(path);
+    std::lock_guard<std::mutex> lock(xstep_mutex());
+    STEPControl_Reader reader;
+
+    if(reader.ReadFile(file.c_str()) != IFSelect_RetDone)
+    {
+        throw Error::io("Failed to read STEP file " + file);
+    }
+
+    reader.TransferRoots();
+
+    if(reader.NbShapes() == 0)
+    {
+        throw Error::translation("No shapes translated from STEP file " + file);
+    }
+
+    auto shapes = std::make_unique<ShapeList>();
+
+    for(int i = 1; i <= reader.NbShapes(); ++i)
+    {
+        shapes->push(scale_shape(reader.Shape(i), 1.0 / unit_scale));
+    }
+
+    return shapes;
+}
+
+void write_step(const ShapeList& shapes, rust::Str path, StepSchema schema, rust::Str unit, double unit_scale)
+{
+    check_input(!shapes.items().empty(), "Nothing to write");
+    check_input(unit_scale > 0.0, "Unit scale must be positive");
+
+    const std::string file(path);
+    const std::string unit_name(unit);
+    std::lock_guard<std::mutex> lock(xstep_mutex());
+
+    // Parameters must be set before the writer creates its model
+    STEPControl_Controller::Init();
+    Interface_Static::SetIVal("write.step.schema", schema_id(schema));
+    Interface_Static::SetCVal("write.step.unit", unit_name.c_str());
+
+    STEPControl_Writer writer;
+
+    for(const TopoDS_Shape& shape : shapes.items())
+    {
+        check_input(!shape.IsNull(), "Can't write null shape");
+
+        if(writer.Transfer(scale_shape(shape, unit_scale), STEPControl_AsIs) != IFSelect_RetDone)
+        {
+            throw Error::translation("Failed to translate shape to STEP");
+        }
+    }
+
+    if(writer.Write(file.c_str()) != IFSelect_RetDone)
+    {
+        throw Error::io("Failed to write STEP file " + file);
+    }
+}
+
+} // namespace occt
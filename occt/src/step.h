@@ -0,0 +1,12 @@
+#pragma once
+
+#include "occt/src/occt.h"
+
+namespace occt {
+
+enum class StepSchema : std::uint8_t;
+
+std::unique_ptr<ShapeList> read_step(rust::Str path, double unit_scale);
+void write_step(const ShapeList& shapes, rust::Str path, StepSchema schema, rust::Str unit, double unit_scale);
+
+} // namespace occt
@@ -0,0 +1,89 @@
+use std::path::Path;
+
+use boolean::shape_list;
+use error::{path_str, OcctError};
+use exchange::LengthUnit;
+use shape::Shape;
+use topology::ShapeIter;
+
+#[cxx::bridge(namespace = "occt")]
+mod ffi {
+    enum StepSchema {
+        Ap203,
+        Ap214,
+        Ap242,
+    }
+
+    unsafe extern "C++" {
+        include!("occt/src/step.h");
+
+        type Shape = crate::occt::ffi::Shape;
+        type ShapeList = crate::occt::ffi::ShapeList;
+
+        fn read_step(path: &str, unit_scale: f64) -> Result<UniquePtr<ShapeList>>;
+        fn write_step(
+            shapes: &ShapeList,
+            path: &str,
+            schema: StepSchema,
+            unit: &str,
+            unit_scale: f64,
+        ) -> Result<()>;
+    }
+}
+
+/// Application protocol written to the STEP header.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StepSchema {
+    Ap203,
+    #[default]
+    Ap214,
+    Ap242,
+}
+
+/// Reads every root of a STEP file, geometry is returned in millimeters.
+pub fn read_step<P: AsRef<Path>>(path: P) -> Result<Vec<Shape>, OcctError> {
+    read_step_with_unit(path, LengthUnit::Millimeter)
+}
+
+/// Reads every root of a STEP file, geometry is converted to `unit` whatever
+/// unit the file was written in.
+pub fn read_step_with_unit<P: AsRef<Path>>(
+    path: P,
+    unit: LengthUnit,
+) -> Result<Vec<Shape>, OcctError> {
+    let list = ffi::read_step(path_str(path.as_ref())?, unit.millimeters())?;
+
+    Ok(ShapeIter::new(list).collect())
+}
+
+/// Writes `shapes` as separate roots, coordinates are taken as millimeters.
+pub fn write_step<P: AsRef<Path>>(
+    shapes: &[Shape],
+    path: P,
+    schema: StepSchema,
+) -> Result<(), OcctError> {
+    write_step_with_unit(shapes, path, schema, LengthUnit::Millimeter)
+}
+
+/// Writes `shapes` whose coordinates are in `unit`, the file declares the same unit.
+pub fn write_step_with_unit<P: AsRef<Path>>(
+    shapes: &[Shape],
+    path: P,
+    schema: StepSchema,
+    unit: LengthUnit,
+) -> Result<(), OcctError> {
+    let schema = match schema {
+        StepSchema::Ap203 => ffi::StepSchema::Ap203,
+        StepSchema::Ap214 => ffi::StepSchema::Ap214,
+        StepSchema::Ap242 => ffi::StepSchema::Ap242,
+    };
+    let list = shape_list(&shapes.iter().collect::<Vec<_>>());
+
+    Ok(ffi::write_step(
+        &list,
+        path_str(path.as_ref())?,
+        schema,
+        unit.xstep_name(),
+        unit.millimeters(),
+    )?)
+}
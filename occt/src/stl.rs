@@ -0,0 +1,499 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use glam::Vec3;
+
+use error::{ErrorKind, OcctError};
+use mesh::{MeshBlob, MeshView};
+use occt::ffi;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StlFormat {
+    #[default]
+    Binary,
+    Ascii,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StlOptions {
+    pub format: StlFormat,
+    /// Derive facet normals from the triangle corners instead of averaging
+    /// the mesh vertex normals.
+    pub recompute_normals: bool,
+    /// Factor applied to coordinates, e.g. 0.001 to write meters from millimeters.
+    pub scale: f64,
+}
+
+impl Default for StlOptions {
+    fn default() -> Self {
+        Self {
+            format: StlFormat::Binary,
+            recompute_normals: false,
+            scale: 1.0,
+        }
+    }
+}
+
+const STL_HEADER: &[u8] = b"occt binary STL";
+
+impl ffi::MeshBlob {
+    pub fn write_stl<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: &StlOptions,
+    ) -> Result<(), OcctError> {
+        let mut out = BufWriter::new(File::create(path)?);
+
+        self.write_stl_to(&mut out, options)?;
+
+        Ok(out.flush()?)
+    }
+
+    pub fn write_stl_to<W: Write>(&self, out: W, options: &StlOptions) -> Result<(), OcctError> {
+        write_stl_mesh(&self.view(), out, options)
+    }
+}
+
+fn write_stl_mesh<W: Write>(
+    mesh: &MeshView,
+    mut out: W,
+    options: &StlOptions,
+) -> Result<(), OcctError> {
+    if !(options.scale.is_finite() && options.scale > 0.0) {
+        return Err(OcctError::new(
+            ErrorKind::InvalidInput,
+            "STL scale must be positive",
+        ));
+    }
+
+    match options.format {
+        StlFormat::Binary => write_stl_binary(mesh, &mut out, options),
+        StlFormat::Ascii => write_stl_ascii(mesh, &mut out, options),
+    }
+}
+
+fn write_stl_binary<W: Write>(
+    mesh: &MeshView,
+    out: &mut W,
+    options: &StlOptions,
+) -> Result<(), OcctError> {
+    let mut header = [0u8; 80];
+
+    header[..STL_HEADER.len()].copy_from_slice(STL_HEADER);
+    out.write_all(&header)?;
+    out.write_all(&(mesh.triangles.len() as u32).to_le_bytes())?;
+
+    for tri in mesh.triangles {
+        let (normal, corners) = stl_facet(mesh, tri, options);
+
+        for v in [normal].iter().chain(corners.iter()) {
+            for c in v.to_array() {
+                out.write_all(&c.to_le_bytes())?;
+            }
+        }
+
+        // Attribute byte count, unused
+        out.write_all(&[0, 0])?;
+    }
+
+    Ok(())
+}
+
+fn write_stl_ascii<W: Write>(
+    mesh: &MeshView,
+    out: &mut W,
+    options: &StlOptions,
+) -> Result<(), OcctError> {
+    writeln!(out, "solid occt")?;
+
+    for tri in mesh.triangles {
+        let (n, corners) = stl_facet(mesh, tri, options);
+
+        writeln!(out, "  facet normal {:e} {:e} {:e}", n.x, n.y, n.z)?;
+        writeln!(out, "    outer loop")?;
+
+        for v in corners {
+            writeln!(out, "      vertex {:e} {:e} {:e}", v.x, v.y, v.z)?;
+        }
+
+        writeln!(out, "    endloop")?;
+        writeln!(out, "  endfacet")?;
+    }
+
+    writeln!(out, "endsolid occt")?;
+
+    Ok(())
+}
+
+fn stl_facet(mesh: &MeshView, tri: &[u32; 3], options: &StlOptions) -> (Vec3, [Vec3; 3]) {
+    let scale = options.scale as f32;
+    let corners = tri.map(|i| Vec3::from(mesh.vertices[i as usize]) * scale);
+
+    let normal = if options.recompute_normals {
+        (corners[1] - corners[0]).cross(corners[2] - corners[0])
+    } else {
+        tri.iter()
+            .map(|&i| Vec3::from(mesh.normals[i as usize]))
+            .sum()
+    };
+
+    // Degenerate facets get a zero normal, readers recompute it
+    (normal.normalize_or_zero(), corners)
+}
+
+/// Reads a binary or ASCII STL file, normals are computed per facet.
+pub fn read_stl<P: AsRef<Path>>(path: P) -> Result<MeshBlob, OcctError> {
+    read_stl_from(BufReader::new(File::open(path)?))
+}
+
+pub fn read_stl_from<R: Read>(mut input: R) -> Result<MeshBlob, OcctError> {
+    let mut data = Vec::new();
+
+    input.read_to_end(&mut data)?;
+
+    let mesh = parse_stl(&data)?;
+
+    Ok(ffi::new_mesh_blob(
+        &mesh.vertices,
+        &mesh.normals,
+        &mesh.triangles,
+    )?)
+}
+
+/// Flat buffers in the layout `new_mesh_blob` takes.
+#[derive(Debug, PartialEq)]
+struct StlMesh {
+    vertices: Vec<f32>,
+    normals: Vec<f32>,
+    triangles: Vec<u32>,
+}
+
+fn parse_stl(data: &[u8]) -> Result<StlMesh, OcctError> {
+    let facets = if is_binary_stl(data) {
+        parse_stl_binary(data)?
+    } else {
+        parse_stl_ascii(data)?
+    };
+
+    if facets.is_empty() {
+        return Err(OcctError::new(ErrorKind::Mesh, "STL file has no facets"));
+    }
+
+    let mut vertices = Vec::with_capacity(facets.len() * 9);
+    let mut normals = Vec::with_capacity(facets.len() * 9);
+
+    // Facets keep their own corners so the flat shading of the source survives
+    for corners in &facets {
+        let normal = (corners[1] - corners[0])
+            .cross(corners[2] - corners[0])
+            .try_normalize()
+            .unwrap_or(Vec3::Z);
+
+        for corner in corners {
+            vertices.extend_from_slice(&corner.to_array());
+            normals.extend_from_slice(&normal.to_array());
+        }
+    }
+
+    let triangles: Vec<u32> = (0..vertices.len() as u32 / 3).collect();
+
+    Ok(StlMesh {
+        vertices,
+        normals,
+        triangles,
+    })
+}
+
+fn is_binary_stl(data: &[u8]) -> bool {
+    let text = data.trim_ascii();
+
+    // A well-formed ASCII file wins even if its length happens to match the
+    // binary facet count read from bytes 80..84
+    if text.starts_with(b"solid") {
+        let last_line = text.rsplit(|&b| b == b'\n').next().unwrap_or_default();
+
+        if last_line.trim_ascii_start().starts_with(b"endsolid") {
+            return false;
+        }
+    }
+
+    // Binary files may start with "solid" too, trust the size when it matches
+    if data.len() >= 84 {
+        let count = u32::from_le_bytes([data[80], data[81], data[82], data[83]]) as usize;
+
+        if data.len() == 84 + count * 50 {
+            return true;
+        }
+    }
+
+    !data.trim_ascii_start().starts_with(b"solid")
+}
+
+fn parse_stl_binary(data: &[u8]) -> Result<Vec<[Vec3; 3]>, OcctError> {
+    let truncated = || OcctError::new(ErrorKind::InvalidInput, "Binary STL file is truncated");
+    let count = data.get(80..84).ok_or_else(truncated)?;
+    let count = u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize;
+    let facets = data.get(84..84 + count * 50).ok_or_else(truncated)?;
+
+    Ok(facets
+        .chunks_exact(50)
+        .map(|facet| {
+            let float = |at: usize| {
+                f32::from_le_bytes([facet[at], facet[at + 1], facet[at + 2], facet[at + 3]])
+            };
+            let point = |at: usize| Vec3::new(float(at), float(at + 4), float(at + 8));
+
+            // Stored normal at offset 0 is skipped, it is recomputed
+            [point(12), point(24), point(36)]
+        })
+        .collect())
+}
+
+fn parse_stl_ascii(data: &[u8]) -> Result<Vec<[Vec3; 3]>, OcctError> {
+    let text = std::str::from_utf8(data).map_err(|_| {
+        OcctError::new(
+            ErrorKind::InvalidInput,
+            "STL file is neither binary nor ASCII",
+        )
+    })?;
+    let malformed = || OcctError::new(ErrorKind::InvalidInput, "Malformed STL vertex");
+    let mut facets = Vec::new();
+    let mut corners = Vec::with_capacity(3);
+
+    for line in text.lines() {
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("vertex") => {
+                let mut coord = || -> Result<f32, OcctError> {
+                    tokens
+                        .next()
+                        .and_then(|t| t.parse().ok())
+                        .ok_or_else(malformed)
+                };
+                let corner = Vec3::new(coord()?, coord()?, coord()?);
+
+                if tokens.next().is_some() {
+                    return Err(malformed());
+                }
+
+                corners.push(corner);
+            }
+            Some("endloop") => {
+                if corners.len() != 3 {
+                    return Err(OcctError::new(
+                        ErrorKind::InvalidInput,
+                        "STL facets must be triangles",
+                    ));
+                }
+
+                facets.push([corners[0], corners[1], corners[2]]);
+                corners.clear();
+            }
+            _ => {}
+        }
+    }
+
+    if !corners.is_empty() {
+        return Err(OcctError::new(
+            ErrorKind::InvalidInput,
+            "STL file ends inside a facet",
+        ));
+    }
+
+    Ok(facets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mesh::fixture;
+
+    fn write_mesh(mesh: &MeshView, options: &StlOptions) -> Vec<u8> {
+        let mut out = Vec::new();
+
+        write_stl_mesh(mesh, &mut out, options).unwrap();
+        out
+    }
+
+    fn write(options: &StlOptions) -> Vec<u8> {
+        write_mesh(&fixture::square(&[]), options)
+    }
+
+    fn float_at(data: &[u8], at: usize) -> f32 {
+        f32::from_le_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]])
+    }
+
+    #[test]
+    fn binary_layout() {
+        let data = write(&StlOptions::default());
+
+        assert_eq!(data.len(), 84 + 2 * 50);
+        assert!(data.starts_with(STL_HEADER));
+        assert_eq!(&data[80..84], &2u32.to_le_bytes());
+
+        // Averaged vertex normal
+        assert_eq!(
+            [
+                float_at(&data, 84),
+                float_at(&data, 88),
+                float_at(&data, 92)
+            ],
+            [0.0, 0.0, 1.0]
+        );
+        // Second corner of the first facet
+        assert_eq!(float_at(&data, 84 + 24), 1.0);
+        assert_eq!(&data[84 + 48..84 + 50], &[0, 0]);
+    }
+
+    #[test]
+    fn recomputed_normals_and_scale() {
+        // Stored normals point away from the winding, recomputing ignores them
+        let flipped = MeshView {
+            normals: &[[0.0, 0.0, -1.0]; 4],
+            ..fixture::square(&[])
+        };
+        let data = write_mesh(
+            &flipped,
+            &StlOptions {
+                recompute_normals: true,
+                scale: 2.0,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            [
+                float_at(&data, 84),
+                float_at(&data, 88),
+                float_at(&data, 92)
+            ],
+            [0.0, 0.0, 1.0]
+        );
+        // Third corner of the first facet is (1, 1, 0) scaled
+        assert_eq!(float_at(&data, 84 + 36), 2.0);
+        assert_eq!(float_at(&data, 84 + 40), 2.0);
+    }
+
+    #[test]
+    fn ascii_layout() {
+        let data = write(&StlOptions {
+            format: StlFormat::Ascii,
+            recompute_normals: true,
+            ..Default::default()
+        });
+        let text = String::from_utf8(data).unwrap();
+        let lines: Vec<&str> = text.lines().map(str::trim).collect();
+
+        assert_eq!(lines.len(), 2 + 2 * 7);
+        assert_eq!(lines[0], "solid occt");
+        assert_eq!(lines[1], "facet normal 0e0 0e0 1e0");
+        assert_eq!(lines[2], "outer loop");
+        assert_eq!(lines[4], "vertex 1e0 0e0 0e0");
+        assert_eq!(lines[15], "endsolid occt");
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let mesh = parse_stl(&write(&StlOptions::default())).unwrap();
+
+        assert_eq!(mesh.triangles, (0..6).collect::<Vec<u32>>());
+        assert_eq!(
+            &mesh.vertices[..9],
+            &[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0]
+        );
+        assert_eq!(
+            &mesh.vertices[9..],
+            &[0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0]
+        );
+        // Stored normals are ignored, facets get their geometric normal
+        assert!(mesh.normals.chunks(3).all(|n| n == [0.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn ascii_round_trip() {
+        let binary = parse_stl(&write(&StlOptions::default())).unwrap();
+        let ascii = parse_stl(&write(&StlOptions {
+            format: StlFormat::Ascii,
+            ..Default::default()
+        }))
+        .unwrap();
+
+        assert_eq!(ascii, binary);
+    }
+
+    #[test]
+    fn binary_header_starting_with_solid() {
+        let mut data = write(&StlOptions::default());
+
+        data[..5].copy_from_slice(b"solid");
+
+        assert!(is_binary_stl(&data));
+        assert_eq!(parse_stl(&data).unwrap().triangles.len(), 6);
+    }
+
+    #[test]
+    fn ascii_matching_binary_size() {
+        // Real text can't put a small count into bytes 80..84, force one facet
+        let mut data = b"solid ".to_vec();
+
+        data.resize(80, b'x');
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.push(b'\n');
+
+        let tail = b"endsolid x\n";
+
+        data.resize(84 + 50 - tail.len(), b' ');
+        data.extend_from_slice(tail);
+
+        assert_eq!(data.len(), 84 + 50);
+        assert!(!is_binary_stl(&data));
+    }
+
+    #[test]
+    fn ascii_errors() {
+        let err = parse_stl(
+            b"solid x\nfacet normal 0 0 1\nouter loop\nvertex 0 0\nendloop\nendsolid x\n",
+        )
+        .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        for vertex in ["vertex 1 x 2 3", "vertex 1 2 3 4", "vertex 1 2"] {
+            let err = parse_stl_ascii(vertex.as_bytes()).unwrap_err();
+
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+
+        let err = parse_stl_ascii(b"solid x\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\n")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        let err = parse_stl(b"solid empty\nendsolid empty\n").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Mesh);
+    }
+
+    #[test]
+    fn truncated_binary() {
+        let data = write(&StlOptions::default());
+        let err = parse_stl_binary(&data[..data.len() - 1]).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn rejects_bad_scale() {
+        for scale in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let options = StlOptions {
+                scale,
+                ..Default::default()
+            };
+            let err = write_stl_mesh(&fixture::square(&[]), Vec::new(), &options).unwrap_err();
+
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+#include "occt/src/occt_p.h"
+
+#include <cmath>
+
+#include <BRepAdaptor_Surface.hxx>
+#include <BRepBuilderAPI_MakeWire.hxx>
+#include <BRepFill_TypeOfContact.hxx>
+#include <BRepOffsetAPI_DraftAngle.hxx>
+#include <BRepOffsetAPI_MakePipeShell.hxx>
+#include <BRepOffsetAPI_ThruSections.hxx>
+#include <BRepPrimAPI_MakePrism.hxx>
+#include <BRepPrimAPI_MakeRevol.hxx>
+#include <BRepTools.hxx>
+#include <gp_Ax1.hxx>
+#include <gp_Pln.hxx>
+#include <TopExp_Explorer.hxx>
+#include <TopoDS.hxx>
+#include <TopoDS_Wire.hxx>
+
+namespace occt {
+
+namespace {
+
+// Faces contribute their boundary, single edges are wrapped in a wire. Pipe shells
+// and loft sections take a single wire, so faces with holes are rejected
+TopoDS_Wire to_wire(const TopoDS_Shape& shape, const char* message)
+{
+    check_input(!shape.IsNull(), message);
+
+    switch(shape.ShapeType())
+    {
+    case TopAbs_WIRE:
+        return TopoDS::Wire(shape);
+    case TopAbs_FACE:
+    {
+        int wires = 0;
+
+        for(TopExp_Explorer it(shape, TopAbs_WIRE); it.More(); it.Next())
+        {
+            ++wires;
+        }
+
+        check_input(wires == 1, "Profile faces with holes are not supported");
+
+        return BRepTools::OuterWire(TopoDS::Face(shape));
+    }
+    case TopAbs_EDGE:
+    {
+        BRepBuilderAPI_MakeWire mk_wire(TopoDS::Edge(shape));
+
+        return TopoDS::Wire(check_done(mk_wire, message));
+    }
+    default:
+        throw Error::invalid_input(message);
+    }
+}
+
+} // namespace
+
+std::unique_ptr<Shape> extrude(const Shape& profile, const Tuple3d& vector)
+{
+    check_input(!profile.is_null(), "Can't extrude null shape");
+    check_input(gp_XYZ(vector.x, vector.y, vector.z).Modulus() > gp::Resolution(), "Extrusion vector must be non-zero");
+
+    BRepPrimAPI_MakePrism mk_prism(profile.get(), to_vec(vector), Standard_True);
+
+    return std::make_unique<Shape>(check_done(mk_prism, "Extrusion failed"));
+}
+
+std::unique_ptr<Shape> extrude_drafted(const Shape& face, double height, double angle)
+{
+    check_input(!face.is_null() && face.get().ShapeType() == TopAbs_FACE, "Drafted extrusion needs a face");
+    check_input(height > 0.0, "Extrusion height must be positive");
+    check_input(std::abs(angle) < M_PI / 2.0, "Draft angle must be within (-pi/2, pi/2)");
+
+    const TopoDS_Face& base = TopoDS::Face(face.get());
+    const BRepAdaptor_Surface surface(base);
+
+    check_input(surface.GetType() == GeomAbs_Plane, "Drafted extrusion needs a planar face");
+
+    const gp_Pln plane = surface.Plane();
+    gp_Dir normal = plane.Axis().Direction();
+
+    if(base.Orientation() == TopAbs_REVERSED)
+    {
+        normal.Reverse();
+    }
+
+    BRepPrimAPI_MakePrism mk_prism(base, gp_Vec(normal) * height, Standard_True);
+    const TopoDS_Shape& prism = check_done(mk_prism, "Drafted extrusion failed");
+
+    if(angle == 0.0)
+    {
+        return std::make_unique<Shape>(prism);
+    }
+
+    // Tilt the side faces about the profile plane, caps stay as they are
+    BRepOffsetAPI_DraftAngle mk_draft(prism);
+
+    for(TopExp_Explorer it(prism, TopAbs_FACE); it.More(); it.Next())
+    {
+        const TopoDS_Face& side = TopoDS::Face(it.Current());
+
+        if(side.IsSame(mk_prism.FirstShape()) || side.IsSame(mk_prism.LastShape()))
+        {
+            continue;
+        }
+
+        mk_draft.Add(side, normal, angle, plane);
+
+        if(!mk_draft.AddDone())
+        {
+            throw Error::not_done("Draft angle can't be applied to a side face");
+        }
+    }
+
+    return std::make_unique<Shape>(check_done(mk_draft, "Drafted extrusion failed"));
+}
+
+std::unique_ptr<Shape> revolve(const Shape& profile, const Tuple3d& origin, const Tuple3d& axis, double angle)
+{
+    check_input(!profile.is_null(), "Can't revolve null shape");
+    check_input(angle > 0.0 && angle <= 2.0 * M_PI, "Revolution angle must be in (0, 2 pi]");
+
+    BRepPrimAPI_MakeRevol mk_revol(profile.get(), gp_Ax1(to_pnt(origin), to_dir(axis)), angle, Standard_True);
+
+    return std::make_unique<Shape>(check_done(mk_revol, "Revolution failed"));
+}
+
+std::unique_ptr<Shape> sweep(const Shape& profile, const Shape& spine, const Shape& aux_spine, const SweepParams& params)
+{
+    BRepOffsetAPI_MakePipeShell mk_pipe(to_wire(spine.get(), "Sweep path must be a wire or an edge"));
+
+    switch(params.mode)
+    {
+    case SweepMode::CorrectedFrenet:
+        mk_pipe.SetMode(Standard_False);
+        break;
+    case SweepMode::Frenet:
+        mk_pipe.SetMode(Standard_True);
+        break;
+    case SweepMode::Discrete:
+        mk_pipe.SetDiscreteMode();
+        break;
+    case SweepMode::Fixed:
+        mk_pipe.SetMode(to_ax2(params.axes));
+        break;
+    case SweepMode::Binormal:
+        mk_pipe.SetMode(to_dir(params.binormal));
+        break;
+    case SweepMode::Auxiliary:
+        mk_pipe.SetMode(to_wire(aux_spine.get(), "Auxiliary spine must be a wire or an edge"),
+            params.curvilinear_equivalence,
+            params.contact ? BRepFill_Contact : BRepFill_NoContact);
+        break;
+    default:
+        throw Error::invalid_input("Unknown sweep mode");
+    }
+
+    switch(params.transition)
+    {
+    case SweepTransition::RightCorner:
+        mk_pipe.SetTransitionMode(BRepBuilderAPI_RightCorner);
+        break;
+    case SweepTransition::RoundCorner:
+        mk_pipe.SetTransitionMode(BRepBuilderAPI_RoundCorner);
+        break;
+    case SweepTransition::Transformed:
+    default:
+        mk_pipe.SetTransitionMode(BRepBuilderAPI_Transformed);
+        break;
+    }
+
+    mk_pipe.Add(to_wire(profile.get(), "Sweep profile must be a face, a wire or an edge"));
+    mk_pipe.Build();
+
+    if(!mk_pipe.IsDone())
+    {
+        throw Error::not_done("Sweep failed");
+    }
+
+    if(params.solid && !mk_pipe.MakeSolid())
+    {
+        throw Error::not_done("Sweep result can't be closed into a solid");
+    }
+
+    return std::make_unique<Shape>(mk_pipe.Shape());
+}
+
+std::unique_ptr<Shape> loft(const ShapeList& sections, bool solid, bool ruled)
+{
+    check_input(sections.items().size() >= 2, "Loft needs at least two sections");
+
+    BRepOffsetAPI_ThruSections mk_loft(solid, ruled);
+
+    for(const TopoDS_Shape& section : sections.items())
+    {
+        // End sections may be points, e.g. for a cone-like tip
+        if(!section.IsNull() && section.ShapeType() == TopAbs_VERTEX)
+        {
+            mk_loft.AddVertex(TopoDS::Vertex(section));
+        }
+        else
+        {
+            mk_loft.AddWire(to_wire(section, "Loft sections must be faces, wires, edges or vertices"));
+        }
+    }
+
+    mk_loft.CheckCompatibility(Standard_True);
+
+    return std::make_unique<Shape>(check_done(mk_loft, "Loft failed"));
+}
+
+} // namespace occt
@@ -0,0 +1,156 @@
+use glam::DVec3;
+
+use boolean::shape_list;
+use error::OcctError;
+use occt::ffi;
+use placement::Placement;
+use shape::Shape;
+
+/// Linear extrusion, faces become solids, wires shells and edges faces.
+pub fn extrude(profile: &Shape, vector: DVec3) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::extrude(
+        profile.as_ffi(),
+        &vector.into(),
+    )?))
+}
+
+/// Extrudes a planar face along its normal with side faces tilted by
+/// `draft_angle` radians about the face plane (`BRepOffsetAPI_DraftAngle`).
+pub fn extrude_with_draft(face: &Shape, height: f64, draft_angle: f64) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::extrude_drafted(
+        face.as_ffi(),
+        height,
+        draft_angle,
+    )?))
+}
+
+/// Revolves `profile` by `angle` radians about the axis through `origin`.
+pub fn revolve(
+    profile: &Shape,
+    origin: DVec3,
+    axis: DVec3,
+    angle: f64,
+) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::revolve(
+        profile.as_ffi(),
+        &origin.into(),
+        &axis.into(),
+        angle,
+    )?))
+}
+
+/// How the profile is oriented along the path (`BRepOffsetAPI_MakePipeShell::SetMode`).
+#[derive(Clone, Copy, Default)]
+pub enum SweepMode<'a> {
+    #[default]
+    CorrectedFrenet,
+    Frenet,
+    /// Discrete trihedron, robust on paths with inflections.
+    Discrete,
+    /// Constant trihedron, the profile is only translated.
+    Fixed(Placement),
+    /// Constant binormal direction, e.g. to sweep a helix around a fixed axis.
+    Binormal(DVec3),
+    /// Profile normal follows the line from the path to a second, guiding path.
+    Auxiliary {
+        spine: &'a Shape,
+        /// Parametrize both paths by curvilinear abscissa instead of normal planes.
+        curvilinear_equivalence: bool,
+        /// Keep the profile in contact with the auxiliary spine.
+        contact: bool,
+    },
+}
+
+/// Treatment of path corners (`BRepBuilderAPI_TransitionMode`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Transition {
+    #[default]
+    Transformed,
+    RightCorner,
+    RoundCorner,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct SweepOptions<'a> {
+    pub mode: SweepMode<'a>,
+    pub transition: Transition,
+    /// Close the result with the end profiles, the profile must be closed.
+    pub solid: bool,
+}
+
+/// Sweeps `profile` along `path`, a wire or an edge. Face profiles are swept
+/// by their boundary and must not have holes.
+pub fn sweep(profile: &Shape, path: &Shape, options: &SweepOptions) -> Result<Shape, OcctError> {
+    let mut params = ffi::SweepParams {
+        mode: ffi::SweepMode::CorrectedFrenet,
+        axes: Placement::default().to_ffi(),
+        binormal: DVec3::Z.into(),
+        curvilinear_equivalence: false,
+        contact: false,
+        transition: match options.transition {
+            Transition::Transformed => ffi::SweepTransition::Transformed,
+            Transition::RightCorner => ffi::SweepTransition::RightCorner,
+            Transition::RoundCorner => ffi::SweepTransition::RoundCorner,
+        },
+        solid: options.solid,
+    };
+    let mut aux_spine = path;
+
+    match options.mode {
+        SweepMode::CorrectedFrenet => {}
+        SweepMode::Frenet => params.mode = ffi::SweepMode::Frenet,
+        SweepMode::Discrete => params.mode = ffi::SweepMode::Discrete,
+        SweepMode::Fixed(placement) => {
+            params.mode = ffi::SweepMode::Fixed;
+            params.axes = placement.to_ffi();
+        }
+        SweepMode::Binormal(binormal) => {
+            params.mode = ffi::SweepMode::Binormal;
+            params.binormal = binormal.into();
+        }
+        SweepMode::Auxiliary {
+            spine,
+            curvilinear_equivalence,
+            contact,
+        } => {
+            params.mode = ffi::SweepMode::Auxiliary;
+            params.curvilinear_equivalence = curvilinear_equivalence;
+            params.contact = contact;
+            aux_spine = spine;
+        }
+    }
+
+    Ok(Shape::from_ffi(ffi::sweep(
+        profile.as_ffi(),
+        path.as_ffi(),
+        aux_spine.as_ffi(),
+        &params,
+    )?))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoftOptions {
+    /// Cap the ends to get a solid, otherwise a shell is produced.
+    pub solid: bool,
+    /// Ruled (straight) faces between sections instead of smooth ones.
+    pub ruled: bool,
+}
+
+impl Default for LoftOptions {
+    fn default() -> Self {
+        Self {
+            solid: true,
+            ruled: false,
+        }
+    }
+}
+
+/// Skins `sections` (faces, wires, edges or end vertices) in order, faces
+/// must not have holes.
+pub fn loft(sections: &[&Shape], options: &LoftOptions) -> Result<Shape, OcctError> {
+    Ok(Shape::from_ffi(ffi::loft(
+        &shape_list(sections),
+        options.solid,
+        options.ruled,
+    )?))
+}
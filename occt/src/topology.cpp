@@ -0,0 +1,166 @@
+#include "occt/src/occt_p.h"
+
+#include <algorithm>
+
+#include <TopExp.hxx>
+#include <TopExp_Explorer.hxx>
+#include <TopTools_IndexedDataMapOfShapeListOfShape.hxx>
+#include <TopTools_IndexedMapOfShape.hxx>
+
+namespace occt {
+
+namespace {
+
+TopAbs_ShapeEnum to_shape_enum(ShapeType kind)
+{
+    switch(kind)
+    {
+    case ShapeType::Compound:
+        return TopAbs_COMPOUND;
+    case ShapeType::CompSolid:
+        return TopAbs_COMPSOLID;
+    case ShapeType::Solid:
+        return TopAbs_SOLID;
+    case ShapeType::Shell:
+        return TopAbs_SHELL;
+    case ShapeType::Face:
+        return TopAbs_FACE;
+    case ShapeType::Wire:
+        return TopAbs_WIRE;
+    case ShapeType::Edge:
+        return TopAbs_EDGE;
+    case ShapeType::Vertex:
+        return TopAbs_VERTEX;
+    default:
+        return TopAbs_SHAPE;
+    }
+}
+
+// Seam edges and closed wires list the same shape twice
+rust::Vec<std::uint32_t> to_indices(std::vector<std::uint32_t>& ids)
+{
+    std::sort(ids.begin(), ids.end());
+    ids.erase(std::unique(ids.begin(), ids.end()), ids.end());
+
+    rust::Vec<std::uint32_t> indices;
+
+    for(std::uint32_t idx : ids)
+    {
+        indices.push_back(idx);
+    }
+
+    return indices;
+}
+
+} // namespace
+
+std::unique_ptr<ShapeList> sub_shapes(const Shape& shape, ShapeType kind) noexcept
+{
+    auto list = std::make_unique<ShapeList>();
+    const TopAbs_ShapeEnum type = to_shape_enum(kind);
+
+    if(shape.is_null() || type == TopAbs_SHAPE)
+    {
+        return list;
+    }
+
+    // Index order of TopExp::MapShapes, the same maps number mesh faces and edges
+    TopTools_IndexedMapOfShape map;
+
+    TopExp::MapShapes(shape.get(), type, map);
+
+    for(int i = 1; i <= map.Extent(); ++i)
+    {
+        list->push(map(i));
+    }
+
+    return list;
+}
+
+std::int64_t sub_shape_index(const Shape& shape, const Shape& sub) noexcept
+{
+    if(shape.is_null() || sub.is_null())
+    {
+        return -1;
+    }
+
+    TopTools_IndexedMapOfShape map;
+
+    TopExp::MapShapes(shape.get(), sub.get().ShapeType(), map);
+
+    // Orientation is ignored, same as the map itself
+    return map.FindIndex(sub.get()) - 1;
+}
+
+rust::Vec<std::uint32_t> related_shapes(const Shape& shape, ShapeType kind, std::uint32_t index, ShapeType related)
+{
+    const TopAbs_ShapeEnum type = to_shape_enum(kind);
+    const TopAbs_ShapeEnum related_type = to_shape_enum(related);
+
+    check_input(!shape.is_null(), "Can't query null shape");
+    check_input(type != TopAbs_SHAPE && related_type != TopAbs_SHAPE, "Sub-shape type must be specific");
+    check_input(type != related_type, "Related type must differ from the sub-shape type");
+
+    TopTools_IndexedMapOfShape map;
+    TopTools_IndexedMapOfShape related_map;
+    std::vector<std::uint32_t> result;
+
+    TopExp::MapShapes(shape.get(), type, map);
+    TopExp::MapShapes(shape.get(), related_type, related_map);
+    check_input(index < static_cast<std::uint32_t>(map.Extent()), "Sub-shape index out of range");
+
+    const TopoDS_Shape& sub = map(static_cast<int>(index) + 1);
+
+    // TopAbs orders types from the largest (compound) to the smallest (vertex)
+    if(related_type < type)
+    {
+        // Ancestor map puts free sub-shapes last, look up by key to keep MapShapes numbering
+        TopTools_IndexedDataMapOfShapeListOfShape ancestors;
+
+        TopExp::MapShapesAndAncestors(shape.get(), type, related_type, ancestors);
+
+        for(const TopoDS_Shape& ancestor : ancestors.FindFromKey(sub))
+        {
+            result.push_back(static_cast<std::uint32_t>(related_map.FindIndex(ancestor) - 1));
+        }
+    }
+    else
+    {
+        for(TopExp_Explorer it(sub, related_type); it.More(); it.Next())
+        {
+            result.push_back(static_cast<std::uint32_t>(related_map.FindIndex(it.Current()) - 1));
+        }
+    }
+
+    return to_indices(result);
+}
+
+rust::Vec<std::uint32_t> face_neighbors(const Shape& shape, std::uint32_t index)
+{
+    check_input(!shape.is_null(), "Can't query null shape");
+
+    TopTools_IndexedMapOfShape faces;
+    TopTools_IndexedDataMapOfShapeListOfShape edge_faces;
+    std::vector<std::uint32_t> result;
+
+    TopExp::MapShapes(shape.get(), TopAbs_FACE, faces);
+    TopExp::MapShapesAndAncestors(shape.get(), TopAbs_EDGE, TopAbs_FACE, edge_faces);
+    check_input(index < static_cast<std::uint32_t>(faces.Extent()), "Sub-shape index out of range");
+
+    for(TopExp_Explorer it(faces(static_cast<int>(index) + 1), TopAbs_EDGE); it.More(); it.Next())
+    {
+        for(const TopoDS_Shape& face : edge_faces.FindFromKey(it.Current()))
+        {
+            const std::uint32_t face_idx = static_cast<std::uint32_t>(faces.FindIndex(face) - 1);
+
+            if(face_idx != index)
+            {
+                result.push_back(face_idx);
+            }
+        }
+    }
+
+    return to_indices(result);
+}
+
+} // namespace occt
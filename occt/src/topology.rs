@@ -0,0 +1,123 @@
+use std::convert::TryFrom;
+use std::iter::FusedIterator;
+
+use cxx::UniquePtr;
+
+use error::OcctError;
+use occt::ffi;
+use shape::{Shape, ShapeType};
+
+/// Sub-shapes in map order, the position of an item is its index.
+pub struct ShapeIter {
+    list: UniquePtr<ffi::ShapeList>,
+    next: usize,
+}
+
+impl ShapeIter {
+    pub(crate) fn new(list: UniquePtr<ffi::ShapeList>) -> Self {
+        Self { list, next: 0 }
+    }
+}
+
+impl Iterator for ShapeIter {
+    type Item = Shape;
+
+    fn next(&mut self) -> Option<Shape> {
+        if self.next >= self.list.len() {
+            return None;
+        }
+
+        self.next += 1;
+
+        self.list.get(self.next - 1).ok().map(Shape::from_ffi)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.list.len() - self.next;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for ShapeIter {}
+
+impl FusedIterator for ShapeIter {}
+
+/// Topology queries, indices are positions in the shape's `TopExp::MapShapes`
+/// map of the given type: the numbering used by `MeshFace::face_index`,
+/// `MeshEdge::edge_index` and `fillet`.
+impl Shape {
+    /// Unique sub-shapes of `kind`, orientation is ignored.
+    pub fn sub_shapes(&self, kind: ShapeType) -> ShapeIter {
+        ShapeIter::new(ffi::sub_shapes(self.as_ffi(), kind.into()))
+    }
+
+    pub fn solids(&self) -> ShapeIter {
+        self.sub_shapes(ShapeType::Solid)
+    }
+
+    pub fn shells(&self) -> ShapeIter {
+        self.sub_shapes(ShapeType::Shell)
+    }
+
+    pub fn faces(&self) -> ShapeIter {
+        self.sub_shapes(ShapeType::Face)
+    }
+
+    pub fn wires(&self) -> ShapeIter {
+        self.sub_shapes(ShapeType::Wire)
+    }
+
+    pub fn edges(&self) -> ShapeIter {
+        self.sub_shapes(ShapeType::Edge)
+    }
+
+    pub fn vertices(&self) -> ShapeIter {
+        self.sub_shapes(ShapeType::Vertex)
+    }
+
+    /// Index of `sub` in the map of its own type, `None` if it is not part of the shape.
+    pub fn index_of(&self, sub: &Shape) -> Option<usize> {
+        usize::try_from(ffi::sub_shape_index(self.as_ffi(), sub.as_ffi())).ok()
+    }
+
+    /// Indices of `related` sub-shapes connected to sub-shape `index` of `kind`:
+    /// ancestors when `related` is a larger type (faces of an edge), descendants
+    /// otherwise (edges of a face).
+    pub fn related(
+        &self,
+        kind: ShapeType,
+        index: usize,
+        related: ShapeType,
+    ) -> Result<Vec<usize>, OcctError> {
+        let index = u32::try_from(index).unwrap_or(u32::MAX);
+        let ids = ffi::related_shapes(self.as_ffi(), kind.into(), index, related.into())?;
+
+        Ok(ids.into_iter().map(|i| i as usize).collect())
+    }
+
+    /// Faces sharing edge `edge`, two for a manifold solid.
+    pub fn edge_faces(&self, edge: usize) -> Result<Vec<usize>, OcctError> {
+        self.related(ShapeType::Edge, edge, ShapeType::Face)
+    }
+
+    pub fn face_edges(&self, face: usize) -> Result<Vec<usize>, OcctError> {
+        self.related(ShapeType::Face, face, ShapeType::Edge)
+    }
+
+    pub fn edge_vertices(&self, edge: usize) -> Result<Vec<usize>, OcctError> {
+        self.related(ShapeType::Edge, edge, ShapeType::Vertex)
+    }
+
+    pub fn vertex_edges(&self, vertex: usize) -> Result<Vec<usize>, OcctError> {
+        self.related(ShapeType::Vertex, vertex, ShapeType::Edge)
+    }
+
+    /// Faces sharing at least one edge with face `face`.
+    pub fn face_neighbors(&self, face: usize) -> Result<Vec<usize>, OcctError> {
+        let face = u32::try_from(face).unwrap_or(u32::MAX);
+        let ids = ffi::face_neighbors(self.as_ffi(), face)?;
+
+        Ok(ids.into_iter().map(|i| i as usize).collect())
+    }
+}
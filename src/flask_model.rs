@@ -68,6 +68,10 @@ impl FlaskModel {
         self.gen.queue_size()
     }
 
+    pub fn last_error(&self) -> Option<occt::OcctError> {
+        self.gen.last_error()
+    }
+
     pub fn draw(
         &mut self,
         model: &glam::Mat4,
@@ -126,6 +130,9 @@ impl FlaskModel {
 
         let mesh = model.mesh;
         let bbox = mesh.bbox();
+        let verts = mesh.vertices();
+        let norms = mesh.normals();
+        let tris = mesh.triangles();
 
         self.object_space = glam::Mat4::from_translation(
             glam::Vec3::new(
@@ -141,8 +148,8 @@ impl FlaskModel {
             gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo_pos);
             gl::BufferData(
                 gl::ARRAY_BUFFER,
-                (mesh.verts().count as usize * 3 * std::mem::size_of::<GLfloat>()) as GLsizeiptr,
-                mesh.verts().ptr as *const GLvoid,
+                std::mem::size_of_val(verts) as GLsizeiptr,
+                verts.as_ptr() as *const GLvoid,
                 gl::STATIC_DRAW,
             );
             gl::VertexAttribPointer(
@@ -158,8 +165,8 @@ impl FlaskModel {
             gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo_norm);
             gl::BufferData(
                 gl::ARRAY_BUFFER,
-                (mesh.norms().count as usize * 3 * std::mem::size_of::<GLfloat>()) as GLsizeiptr,
-                mesh.norms().ptr as *const GLvoid,
+                std::mem::size_of_val(norms) as GLsizeiptr,
+                norms.as_ptr() as *const GLvoid,
                 gl::STATIC_DRAW,
             );
             gl::VertexAttribPointer(
@@ -175,11 +182,11 @@ impl FlaskModel {
             gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, self.vbo_tri);
             gl::BufferData(
                 gl::ELEMENT_ARRAY_BUFFER,
-                (mesh.tris().count as usize * 3 * std::mem::size_of::<GLuint>()) as GLsizeiptr,
-                mesh.tris().ptr as *const GLvoid,
+                std::mem::size_of_val(tris) as GLsizeiptr,
+                tris.as_ptr() as *const GLvoid,
                 gl::STATIC_DRAW,
             );
-            self.vbo_tri_elements_n = mesh.tris().count * 3;
+            self.vbo_tri_elements_n = (tris.len() * 3) as GLuint;
         }
     }
 }
@@ -195,7 +202,8 @@ struct ModelGenerator {
     beat_interval: u32,
     // Number of enqueued mesh generations
     enqueue_size: Arc<AtomicU32>,
-    curr_model_sync: Arc<Mutex<(u32, Option<GeneratedModel>)>>,
+    // Latest model timestamp, model not yet uploaded and last generation failure
+    curr_model_sync: Arc<Mutex<(u32, Option<GeneratedModel>, Option<occt::OcctError>)>>,
 }
 
 impl Drop for ModelGenerator {
@@ -221,7 +229,7 @@ impl ModelGenerator {
             max_queue_size: max_queue_size,
             beat_interval: beat_interval,
             enqueue_size: Arc::new(AtomicU32::new(0)),
-            curr_model_sync: Arc::new(Mutex::new((0, None))),
+            curr_model_sync: Arc::new(Mutex::new((0, None, None))),
         }
     }
 
@@ -233,6 +241,10 @@ impl ModelGenerator {
         self.enqueue_size.load(Ordering::Relaxed) as u32
     }
 
+    fn last_error(&self) -> Option<occt::OcctError> {
+        self.curr_model_sync.lock().unwrap().2.clone()
+    }
+
     fn dequeue(&mut self, ts: u32) -> Option<GeneratedModel> {
         if self.enqueue_size.load(Ordering::Acquire) < self.max_queue_size {
             self.enqueue_size.fetch_add(1, Ordering::Relaxed);
@@ -255,19 +267,28 @@ impl ModelGenerator {
                 }
 
                 let delta = (gen_ts % beat_interval) as f64 / beat_interval as f64;
-                let mesh = occt::make_flask(
+                let mesh = occt::make_flask(&occt::FlaskParams::new(
                     Self::lerp_f64(width.0, width.1, delta),
                     Self::lerp_f64(thickness.0, thickness.1, delta),
                     Self::lerp_f64(height.0, height.1, delta),
-                );
+                ));
 
                 let mut curr_model = curr_model_sync.lock().unwrap();
 
-                if curr_model.0 <= ts {
-                    curr_model.0 = ts;
-                    curr_model.1 = Some(GeneratedModel { ts: ts, mesh: mesh });
+                match mesh {
+                    Ok(mesh) => {
+                        if curr_model.0 <= ts {
+                            curr_model.0 = ts;
+                            curr_model.1 = Some(GeneratedModel { ts: ts, mesh: mesh });
+                            curr_model.2 = None;
+                        }
+                    }
+                    // Skip bad configuration, keep showing the previous model
+                    Err(err) => curr_model.2 = Some(err),
                 }
 
+                drop(curr_model);
+
                 enq_size.fetch_sub(1, Ordering::Release);
             });
         }
@@ -41,6 +41,10 @@ fn main() {
         );
 
         // Draw stats
+        if let Some(err) = model.last_error() {
+            gfx.draw_text_at_bottom_left(format!("Error {}", err).as_str(), 5);
+        }
+
         gfx.draw_text_at_bottom_left(format!("Frame {:9}", frame_num).as_str(), 4);
 
         let total_milliseconds = frame_num * FPS.1 * 1000 / FPS.0;